@@ -0,0 +1,65 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U64,
+    require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// The maximum length (in bytes) of the reference given when blocklisting an account.
+pub(crate) const MAX_BLOCKLIST_REFERENCE_LENGTH: usize = 256;
+
+/// Defines why an account was blocklisted. *New reasons must be added to the bottom of the enum
+/// list*, otherwise the stored blocklist entries will change meaning.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub enum BlocklistReason {
+    Sanctions,
+    CourtOrder,
+    Fraud,
+    Other,
+}
+
+/// Records why, when and by whom an account was blocklisted, so that auditors can reconstruct why
+/// its funds are frozen. Accounts blocklisted before these records were kept have none.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct BlocklistEntry {
+    pub reason: Option<BlocklistReason>,
+    /// Context for the reason, e.g. a case number or the reference of the sanctions list.
+    pub reference: Option<String>,
+    /// The blocklister that blocklisted the account.
+    pub blocklister_id: AccountId,
+    /// Block timestamp (in nanoseconds) at which the account was blocklisted.
+    pub blocklisted_at: U64,
+}
+
+impl BlocklistEntry {
+    /// Verifies that the reference does not exceed its length limit.
+    pub(crate) fn validate(&self) {
+        if let Some(reference) = &self.reference {
+            require!(
+                reference.len() <= MAX_BLOCKLIST_REFERENCE_LENGTH,
+                format!(
+                    "FiatToken: reference cannot be longer than {MAX_BLOCKLIST_REFERENCE_LENGTH} bytes"
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use near_sdk::{ext_contract, AccountId, Gas};
+
+/// Gas attached to a call to the compliance oracle's [`ComplianceOracle::are_blocklisted`].
+pub(crate) const GAS_FOR_COMPLIANCE_ORACLE_CALL: Gas = Gas(5_000_000_000_000);
+
+/// Gas reserved for the callback mirroring the compliance oracle's answer.
+pub(crate) const GAS_FOR_ORACLE_BLOCKLIST_SYNC: Gas = Gas(10_000_000_000_000);
+
+/// Interface of the shared sanctions registry contract that can be configured as the token's
+/// compliance oracle, so that a single blocklist can be used by several tokens.
+#[ext_contract(ext_compliance_oracle)]
+pub trait ComplianceOracle {
+    /// Returns, for each of the given accounts, whether it is blocklisted by the oracle.
+    fn are_blocklisted(&self, account_ids: Vec<AccountId>) -> Vec<bool>;
+}
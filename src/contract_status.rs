@@ -0,0 +1,39 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::multisig_configuration::MultisigConfigurationView;
+use crate::pausable_operation::PausableOperation;
+use near_sdk::{
+    json_types::U64,
+    serde::{Deserialize, Serialize},
+};
+
+/// Read-only summary of the contract's state, used for monitoring.
+#[derive(Serialize, Deserialize)]
+pub struct ContractStatusView {
+    /// Version of the contract code, as defined in its Cargo manifest.
+    pub version: String,
+    /// The operations that are currently paused.
+    pub paused_operations: Vec<PausableOperation>,
+    pub approved_for_upgrade: bool,
+    /// Whether only allowlisted accounts can transfer, receive, mint or register.
+    pub allowlist_enabled: bool,
+    /// The multi-sig settings applying to the requests of each multi-sig Role.
+    pub multisig_configurations: Vec<MultisigConfigurationView>,
+    /// Minimum delay between a timelocked multi-sig request reaching its approval threshold and
+    /// being executable. 0 = no timelock.
+    pub multisig_timelock_nanoseconds: U64,
+}
@@ -15,11 +15,40 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod fiat_token_event {
+    use crate::blocklist_entry::BlocklistReason;
+    use crate::fiat_token_action::FiatTokenAction;
+    use crate::pausable_operation::PausableOperation;
     use crate::role::Role;
-    use near_sdk::json_types::U128;
+    use crate::supply_cap_policy::SupplyCapPolicy;
+    use near_sdk::json_types::{Base64VecU8, U128, U64};
     use near_sdk::AccountId;
     use near_sdk_contract_tools::event;
 
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when account ID is allowlisted.
+    pub struct Allowlist {
+        pub account_id: AccountId,
+        pub allowlist_manager_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when an account is granted the AllowlistManager role.
+    pub struct AllowlistManagerAdded {
+        pub allowlist_manager_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the AllowlistManager role is revoked from an account.
+    pub struct AllowlistManagerRemoved {
+        pub allowlist_manager_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when allowlist mode is switched on or off.
+    pub struct AllowlistModeUpdated {
+        pub enabled: bool,
+    }
+
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when approving a spender account to spend an allowance from a holder's account.
     pub struct Approve {
@@ -32,10 +61,14 @@ pub mod fiat_token_event {
     /// Emitted when contract is approved for upgrade.
     pub struct ApprovedForUpgrade;
 
-    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    #[event(standard = "x-fiat-token", version = "1.1.0", rename = "snake_case")]
     /// Emitted when account ID is blocklisted.
     pub struct Blocklist {
         pub account_id: AccountId,
+        pub blocklister_id: AccountId,
+        pub reason: Option<BlocklistReason>,
+        pub reference: Option<String>,
+        pub blocklisted_at: U64,
     }
 
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
@@ -44,6 +77,33 @@ pub mod fiat_token_event {
         pub new_blocklister_id: AccountId,
     }
 
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the funds of a blocklisted account are burned, or moved to the `destination`
+    /// custody account.
+    pub struct BlocklistedFundsWiped {
+        pub account_id: AccountId,
+        pub destination: Option<AccountId>,
+        pub amount: U128,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when an account is granted the Blocklister role alongside the existing blocklisters.
+    pub struct BlocklisterAdded {
+        pub blocklister_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the Blocklister role is revoked from an account.
+    pub struct BlocklisterRemoved {
+        pub blocklister_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the compliance oracle is set, replaced or removed.
+    pub struct ComplianceOracleUpdated {
+        pub compliance_oracle: Option<AccountId>,
+    }
+
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when a controller is configured with a minter.
     pub struct ControllerConfigured {
@@ -57,6 +117,14 @@ pub mod fiat_token_event {
         pub controller_id: AccountId,
     }
 
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the max total supply, or the policy applied to minter allowances exceeding it,
+    /// is changed.
+    pub struct MaxTotalSupplyUpdated {
+        pub max_total_supply: Option<U128>,
+        pub policy: SupplyCapPolicy,
+    }
+
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when minter account ID is configured.
     pub struct MinterConfigured {
@@ -64,6 +132,25 @@ pub mod fiat_token_event {
         pub minter_allowance: U128,
     }
 
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted under the Warn [`SupplyCapPolicy`] when a minter allowance is configured or increased
+    /// such that the outstanding minter allowances plus the total supply exceed the max total
+    /// supply.
+    pub struct MinterAllowancesExceedMaxTotalSupply {
+        pub minter_allowances: U128,
+        pub total_supply: U128,
+        pub max_total_supply: U128,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the rate limit of a minter's allowance is configured. A `replenish_per_second`
+    /// of 0 means the rate limit was removed.
+    pub struct MinterRateLimitConfigured {
+        pub minter_id: AccountId,
+        pub max_allowance: U128,
+        pub replenish_per_second: U128,
+    }
+
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when minter account ID is removed.
     pub struct MinterRemoved {
@@ -71,14 +158,108 @@ pub mod fiat_token_event {
     }
 
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when an account withdraws its approval of a multisignature transaction.
+    pub struct MultisigApprovalRevoked {
+        pub request_id: u32,
+        pub account_id: AccountId,
+        pub approval_count: u32,
+        pub action: FiatTokenAction,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the multi-sig approval threshold and/or validity period is changed, either
+    /// for a specific role or for the contract-wide default (`role` is null).
+    pub struct MultisigConfigurationUpdated {
+        pub role: Option<Role>,
+        pub old_threshold: u8,
+        pub new_threshold: u8,
+        pub old_validity_period_nanoseconds: U64,
+        pub new_validity_period_nanoseconds: U64,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when a multisignature transaction is approved by an account.
+    pub struct MultisigRequestApproved {
+        pub request_id: u32,
+        pub account_id: AccountId,
+        pub approval_count: u32,
+        pub action: FiatTokenAction,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when a timelocked multisignature transaction is cancelled.
+    pub struct MultisigRequestCancelled {
+        pub request_id: u32,
+        pub account_id: AccountId,
+        pub approval_count: u32,
+        pub action: FiatTokenAction,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.1.0", rename = "snake_case")]
     /// Emitted when a multisignature transaction is created.
     pub struct MultisigRequestCreated {
         pub request_id: u32,
+        pub creator_id: AccountId,
+        pub action: FiatTokenAction,
+        pub description: Option<String>,
+        pub reference_hash: Option<Base64VecU8>,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when a multisignature transaction is executed.
+    pub struct MultisigRequestExecuted {
+        pub request_id: u32,
+        pub account_id: AccountId,
+        pub approval_count: u32,
+        pub action: FiatTokenAction,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when a multisignature transaction has exceeded its validity period without being
+    /// executed. As expiry happens passively, this is emitted when the request is removed.
+    pub struct MultisigRequestExpired {
+        pub request_id: u32,
+        pub expired_at: U64,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when a multisignature transaction is removed.
+    pub struct MultisigRequestRemoved {
+        pub request_id: u32,
+        pub account_id: AccountId,
+        pub approval_count: u32,
+        pub action: FiatTokenAction,
     }
 
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
-    /// Emitted when contract is paused.
-    pub struct Paused;
+    /// Emitted when a timelocked multisignature transaction reaches its approval threshold,
+    /// starting its timelock.
+    pub struct MultisigTimelockStarted {
+        pub request_id: u32,
+        pub executable_at: U64,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the timelock delay of sensitive multisignature transactions is changed.
+    pub struct MultisigTimelockUpdated {
+        pub old_delay_nanoseconds: U64,
+        pub new_delay_nanoseconds: U64,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when syncing from the compliance oracle changes whether an account is blocklisted
+    /// by it.
+    pub struct OracleBlocklistSynced {
+        pub compliance_oracle: AccountId,
+        pub account_id: AccountId,
+        pub blocklisted: bool,
+    }
+
+    #[event(standard = "x-fiat-token", version = "2.0.0", rename = "snake_case")]
+    /// Emitted when operations of the contract are paused, listing the newly paused operations.
+    pub struct Paused {
+        pub operations: Vec<PausableOperation>,
+    }
 
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when an account is configured as one of the contract's main multi-sig roles, e.g.
@@ -88,6 +269,32 @@ pub mod fiat_token_event {
         pub account_id: AccountId,
     }
 
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when one of the contract's main multi-sig roles, e.g. Admin, MasterMinter, etc. is
+    /// offered to an account, which must accept it before `expires_at`.
+    pub struct RoleOffered {
+        pub role: Role,
+        pub account_id: AccountId,
+        pub expires_at: U64,
+        pub role_expires_at: Option<U64>,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when an account accepts an offered role, and is configured as one of the contract's
+    /// main multi-sig roles.
+    pub struct RoleOfferAccepted {
+        pub role: Role,
+        pub account_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when an expired role offer is removed without having been accepted.
+    pub struct RoleOfferExpired {
+        pub role: Role,
+        pub account_id: AccountId,
+        pub expired_at: U64,
+    }
+
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
     /// Emitted when one of the contract's main multi-sig roles, e.g. Admin, MasterMinter, etc.
     /// is revoked from their role.
@@ -97,12 +304,29 @@ pub mod fiat_token_event {
     }
 
     #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when the pairs of roles that a single account cannot hold at the same time are
+    /// changed.
+    pub struct RoleConflictsUpdated {
+        pub role_conflicts: Vec<(Role, Role)>,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
+    /// Emitted when account ID is unallowlisted.
+    pub struct Unallowlist {
+        pub account_id: AccountId,
+        pub allowlist_manager_id: AccountId,
+    }
+
+    #[event(standard = "x-fiat-token", version = "1.1.0", rename = "snake_case")]
     /// Emitted when account ID is unblocklisted.
     pub struct Unblocklist {
         pub account_id: AccountId,
+        pub blocklister_id: AccountId,
     }
 
-    #[event(standard = "x-fiat-token", version = "1.0.0", rename = "snake_case")]
-    /// Emitted when contract is unpaused.
-    pub struct Unpaused;
+    #[event(standard = "x-fiat-token", version = "2.0.0", rename = "snake_case")]
+    /// Emitted when operations of the contract are unpaused, listing the newly unpaused operations.
+    pub struct Unpaused {
+        pub operations: Vec<PausableOperation>,
+    }
 }
@@ -29,7 +29,8 @@ NOTES:
 use near_contract_standards::fungible_token::{
     core::FungibleTokenCore,
     metadata::{FungibleTokenMetadata, FungibleTokenMetadataProvider},
-    resolver::FungibleTokenResolver,
+    receiver::ext_ft_receiver,
+    resolver::{ext_ft_resolver, FungibleTokenResolver},
     FungibleToken,
 };
 use near_contract_standards::storage_management::{
@@ -39,29 +40,77 @@ use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     collections::LazyOption,
     env,
-    json_types::U128,
+    json_types::{Base64VecU8, U128, U64},
     log, near_bindgen, require,
-    store::UnorderedMap,
-    AccountId, Balance, PanicOnDefault, Promise, PromiseOrValue,
+    store::{LookupSet, UnorderedMap},
+    AccountId, Balance, Gas, PanicOnDefault, Promise, PromiseError, PromiseOrValue,
 };
 use near_sdk_contract_tools::{
     approval::ApprovalManagerInternal, upgrade::serialized::UpgradeHook,
 };
 use near_sdk_contract_tools::{
     approval::{
-        simple_multisig::{ApprovalState, Configuration},
-        ApprovalManager,
+        simple_multisig::{
+            AccountAuthorizer, ApprovalState, Configuration, ExecutionEligibilityError,
+            RequestExpiredError,
+        },
+        Action, ActionRequest, ApprovalConfiguration, ApprovalError, ApprovalManager,
+        ExecutionError, RemovalError,
     },
     rbac::Rbac,
     standard::nep297::Event,
     Rbac, SimpleMultisig, Upgrade,
 };
 
+use crate::blocklist_entry::{BlocklistEntry, BlocklistReason};
+use crate::compliance_oracle::{
+    ext_compliance_oracle, GAS_FOR_COMPLIANCE_ORACLE_CALL, GAS_FOR_ORACLE_BLOCKLIST_SYNC,
+};
+use crate::contract_status::ContractStatusView;
 use crate::events::fiat_token_event;
-use crate::fiat_token_action::FiatTokenAction;
+use crate::fiat_token_action::{BatchActions, FiatTokenAction};
 use crate::fiat_token_storage_key::FiatTokenStorageKey;
-use crate::requires::{require_not_blocklisted, require_only};
-use crate::role::Role;
+use crate::minter_rate_limit::{MinterRateLimit, MinterRateLimitView};
+use crate::multisig_configuration::MultisigConfigurationView;
+use crate::multisig_request::{MultisigRequestMetadata, MultisigRequestView};
+use crate::pausable_operation::{PausableOperation, PAUSABLE_OPERATIONS};
+use crate::requires::{
+    has_active_role, require_any, require_not_blocklisted, require_only,
+    with_multisig_execution_roles,
+};
+use crate::role::{Role, DEFAULT_ROLE_CONFLICTS, MULTISIG_ROLES, ROLES};
+use crate::role_expiration::{get_role_expires_at, is_role_expired, set_role_expires_at};
+use crate::role_offer::{RoleOffer, RoleOfferView};
+use crate::supply_cap_policy::SupplyCapPolicy;
+
+/// The error reported when an account is not authorized to act on multi-signature requests.
+type MultisigAuthorizationError = <Contract as AccountAuthorizer>::AuthorizationError;
+
+/// The maximum number of entries returned by a single call to a paginated view function.
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// The maximum number of request IDs scanned by a single call to
+/// [`Contract::list_multisig_requests`], so that its gas stays bounded however many requests have
+/// already been executed or removed.
+const MAX_MULTISIG_REQUEST_IDS_SCANNED: u32 = 500;
+
+/// The maximum number of accounts that can be blocklisted, unblocklisted, allowlisted or
+/// unallowlisted by a single call.
+const MAX_ACCOUNT_BATCH_SIZE: usize = 100;
+
+/// The period during which an offered [`Role`] can be accepted: 5 days in nanoseconds.
+const ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS: u64 = 432000000000000;
+
+/// Gas reserved for `ft_resolve_transfer`, as in `near-contract-standards`.
+const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(5_000_000_000_000);
+
+/// Gas reserved by `ft_transfer_call` for itself and `ft_resolve_transfer`, the rest being
+/// attached to the receiver's `ft_on_transfer`, as in `near-contract-standards`.
+const GAS_FOR_FT_TRANSFER_CALL: Gas = Gas(25_000_000_000_000 + GAS_FOR_RESOLVE_TRANSFER.0);
+
+/// Gas reserved for the callback performing an `ft_transfer_call` once the compliance oracle has
+/// screened its parties.
+const GAS_FOR_SCREENED_FT_TRANSFER_CALL: Gas = Gas(10_000_000_000_000 + GAS_FOR_FT_TRANSFER_CALL.0);
 
 /// Defines the multi-sig requests/actual behavior of what each [`FiatTokenAction`] will do.
 impl near_sdk_contract_tools::approval::Action<Contract> for FiatTokenAction {
@@ -74,13 +123,13 @@ impl near_sdk_contract_tools::approval::Action<Contract> for FiatTokenAction {
                 controller_id,
                 minter_id,
             } => {
-                contract.configure_controller(controller_id, minter_id);
+                contract.configure_controller(controller_id, minter_id, None);
             }
             FiatTokenAction::ConfigureMinterAllowance {
                 minter_allowance, ..
             } => contract.configure_minter_allowance(minter_allowance),
             FiatTokenAction::ConfigureMultisigRole { role, account_id } => {
-                contract.configure_multisig_role(role, account_id)
+                contract.configure_multisig_role(role, account_id, None)
             }
             FiatTokenAction::DecreaseMinterAllowance { decrement, .. } => {
                 contract.decrease_minter_allowance(decrement)
@@ -100,6 +149,67 @@ impl near_sdk_contract_tools::approval::Action<Contract> for FiatTokenAction {
                 contract.update_blocklister(new_blocklister_id)
             }
             FiatTokenAction::Unpause => contract.unpause(),
+            FiatTokenAction::SetPauseFlags { paused_operations } => {
+                contract.set_pause_flags(paused_operations)
+            }
+            FiatTokenAction::UpdateMultisigConfiguration {
+                role,
+                threshold,
+                validity_period_nanoseconds,
+            } => {
+                contract.update_multisig_configuration(role, threshold, validity_period_nanoseconds)
+            }
+            FiatTokenAction::UpdateMultisigTimelock { delay_nanoseconds } => {
+                contract.update_multisig_timelock(delay_nanoseconds)
+            }
+            FiatTokenAction::UpdateRoleConflicts { role_conflicts } => {
+                contract.update_role_conflicts(role_conflicts)
+            }
+            FiatTokenAction::AddBlocklister { blocklister_id } => {
+                contract.add_blocklister(blocklister_id)
+            }
+            FiatTokenAction::RemoveBlocklister { blocklister_id } => {
+                contract.remove_blocklister(blocklister_id)
+            }
+            FiatTokenAction::WipeBlocklistedFunds {
+                account_id,
+                destination,
+            } => contract.wipe_blocklisted_funds(account_id, destination),
+            FiatTokenAction::SetAllowlistMode { enabled } => contract.set_allowlist_mode(enabled),
+            FiatTokenAction::AddAllowlistManager {
+                allowlist_manager_id,
+            } => contract.add_allowlist_manager(allowlist_manager_id),
+            FiatTokenAction::RemoveAllowlistManager {
+                allowlist_manager_id,
+            } => contract.remove_allowlist_manager(allowlist_manager_id),
+            FiatTokenAction::UpdateComplianceOracle { compliance_oracle } => {
+                contract.update_compliance_oracle(compliance_oracle)
+            }
+            FiatTokenAction::ConfigureMinterRateLimit {
+                max_allowance,
+                replenish_per_second,
+                ..
+            } => contract.configure_minter_rate_limit(max_allowance, replenish_per_second),
+            FiatTokenAction::UpdateMaxTotalSupply {
+                max_total_supply,
+                policy,
+            } => contract.update_max_total_supply(max_total_supply, policy),
+            FiatTokenAction::ConfigureControllerUntil {
+                controller_id,
+                minter_id,
+                expires_at,
+            } => contract.configure_controller(controller_id, minter_id, Some(expires_at)),
+            FiatTokenAction::ConfigureMultisigRoleUntil {
+                role,
+                account_id,
+                expires_at,
+            } => contract.configure_multisig_role(role, account_id, Some(expires_at)),
+            // Actions are executed in order, and any panicking action reverts the whole batch.
+            FiatTokenAction::Batch(BatchActions(actions)) => {
+                for action in actions {
+                    action.execute(contract);
+                }
+            }
         }
     }
 }
@@ -115,15 +225,29 @@ pub struct Contract {
     allowed: UnorderedMap<AccountId, UnorderedMap<AccountId, U128>>,
     controllers: UnorderedMap<AccountId, AccountId>,
     minter_allowed: UnorderedMap<AccountId, U128>,
-    blocklister: AccountId,
-    paused: bool,
+    paused_flags: u8,
     approved_for_upgrade: bool,
+    multisig_configurations: UnorderedMap<Role, Configuration<Contract>>,
+    multisig_timelock_nanoseconds: u64,
+    multisig_threshold_reached_at: UnorderedMap<u32, u64>,
+    multisig_request_metadata: UnorderedMap<u32, MultisigRequestMetadata>,
+    role_conflicts: Vec<(Role, Role)>,
+    role_offers: UnorderedMap<(AccountId, Role), RoleOffer>,
+    blocklist_entries: UnorderedMap<AccountId, BlocklistEntry>,
+    allowlist_enabled: bool,
+    compliance_oracle: Option<AccountId>,
+    oracle_blocklist: LookupSet<(AccountId, AccountId)>,
+    minter_rate_limits: UnorderedMap<AccountId, MinterRateLimit>,
+    max_total_supply: Option<Balance>,
+    supply_cap_policy: SupplyCapPolicy,
 }
 
 #[near_bindgen]
 impl Contract {
     /// Initialize the Contract struct. The `#[init]` decorator also checks if the contract already
     /// exists in the environment.
+    /// `role_conflicts` lists the pairs of [`Role`]s that a single account cannot hold at the same
+    /// time, and defaults to [`DEFAULT_ROLE_CONFLICTS`].
     #[init]
     pub fn init(
         admin_ids: Vec<AccountId>,
@@ -132,6 +256,7 @@ impl Contract {
         pauser_ids: Vec<AccountId>,
         blocklister_id: AccountId,
         metadata: FungibleTokenMetadata,
+        role_conflicts: Option<Vec<(Role, Role)>>,
     ) -> Self {
         // Configure the multi-sig settings: An approval threshold requiring at least that amount
         // to execute a request, and a validity period requiring that a request cannot be executed,
@@ -149,9 +274,27 @@ impl Contract {
             allowed: UnorderedMap::new(FiatTokenStorageKey::Allowed),
             controllers: UnorderedMap::new(FiatTokenStorageKey::Controllers),
             minter_allowed: UnorderedMap::new(FiatTokenStorageKey::MinterAllowed),
-            blocklister: blocklister_id.clone(),
-            paused: false,
+            paused_flags: 0,
             approved_for_upgrade: false,
+            multisig_configurations: UnorderedMap::new(FiatTokenStorageKey::MultisigConfigurations),
+            multisig_timelock_nanoseconds: 0,
+            multisig_threshold_reached_at: UnorderedMap::new(
+                FiatTokenStorageKey::MultisigThresholdReachedAt,
+            ),
+            multisig_request_metadata: UnorderedMap::new(
+                FiatTokenStorageKey::MultisigRequestMetadata,
+            ),
+            role_conflicts: Self::validate_role_conflicts(
+                role_conflicts.unwrap_or_else(|| DEFAULT_ROLE_CONFLICTS.to_vec()),
+            ),
+            role_offers: UnorderedMap::new(FiatTokenStorageKey::RoleOffers),
+            blocklist_entries: UnorderedMap::new(FiatTokenStorageKey::BlocklistEntries),
+            allowlist_enabled: false,
+            compliance_oracle: None,
+            oracle_blocklist: LookupSet::new(FiatTokenStorageKey::OracleBlocklist),
+            minter_rate_limits: UnorderedMap::new(FiatTokenStorageKey::MinterRateLimits),
+            max_total_supply: None,
+            supply_cap_policy: SupplyCapPolicy::Ignore,
         };
 
         this.init_multisig_roles(admin_ids, &Role::Admin);
@@ -183,10 +326,123 @@ impl Contract {
             .unwrap_or(&U128::from(0))
     }
 
-    /// Gets minter allowance for an account.
+    /// Gets minter allowance for an account, including what has been replenished so far if the
+    /// allowance is rate limited.
     /// * `minter_id`  - The address of the minter.
     pub fn minter_allowance(&self, minter_id: &AccountId) -> U128 {
-        *self.minter_allowed.get(minter_id).unwrap_or(&U128::from(0))
+        let minter_allowance: U128 = *self.minter_allowed.get(minter_id).unwrap_or(&U128::from(0));
+        match self.minter_rate_limits.get(minter_id) {
+            Some(rate_limit) => {
+                U128::from(rate_limit.replenish(minter_allowance.0, env::block_timestamp()))
+            }
+            None => minter_allowance,
+        }
+    }
+
+    /// Retrieves the rate limit of a minter's allowance, if it replenishes over time.
+    /// * `minter_id`   - The minter's address.
+    pub fn minter_rate_limit(&self, minter_id: &AccountId) -> Option<MinterRateLimitView> {
+        self.minter_rate_limits
+            .get(minter_id)
+            .map(|rate_limit| MinterRateLimitView {
+                max_allowance: U128::from(rate_limit.max_allowance),
+                replenish_per_second: U128::from(rate_limit.replenish_per_second),
+            })
+    }
+
+    /// Private function to store a minter's allowance. When the allowance replenishes over time,
+    /// the replenishment restarts from the stored allowance, keeping the time accrued towards the
+    /// next replenished unit.
+    /// * `minter_id`           - The minter's address.
+    /// * `minter_allowance`    - The new minter allowance.
+    fn set_minter_allowance(&mut self, minter_id: AccountId, minter_allowance: U128) {
+        if let Some(rate_limit) = self.minter_rate_limits.get_mut(&minter_id) {
+            let stored_allowance: u128 = self.minter_allowed.get(&minter_id).map_or(0, |a| a.0);
+            rate_limit.updated_at =
+                rate_limit.replenished_at(stored_allowance, env::block_timestamp());
+        }
+        self.minter_allowed.insert(minter_id, minter_allowance);
+    }
+
+    /// Caps the total supply that minting can reach, e.g. to the supply attested by the reserves.
+    /// The cap can be set below the current total supply, in which case minting fails until
+    /// enough tokens are burned.
+    /// * `max_total_supply`    - The cap, or `None` to lift it.
+    /// * `policy`              - What happens when configuring or increasing a minter allowance
+    /// brings the outstanding minter allowances plus the total supply above the cap.
+    fn update_max_total_supply(&mut self, max_total_supply: Option<U128>, policy: SupplyCapPolicy) {
+        require_only(Role::Owner);
+        self.max_total_supply = max_total_supply.map(|max_total_supply| max_total_supply.0);
+        self.supply_cap_policy = policy;
+        fiat_token_event::MaxTotalSupplyUpdated {
+            max_total_supply,
+            policy,
+        }
+        .emit();
+    }
+
+    /// Returns the cap on the total supply, if any.
+    pub fn max_total_supply(&self) -> Option<U128> {
+        self.max_total_supply.map(U128::from)
+    }
+
+    /// Returns what happens when minter allowances could exceed the max total supply.
+    pub fn supply_cap_policy(&self) -> SupplyCapPolicy {
+        self.supply_cap_policy
+    }
+
+    /// Returns the amount of tokens that can still be minted before reaching the max total supply,
+    /// or `None` if the total supply is not capped.
+    pub fn supply_headroom(&self) -> Option<U128> {
+        self.max_total_supply.map(|max_total_supply| {
+            U128::from(max_total_supply.saturating_sub(self.token.total_supply))
+        })
+    }
+
+    /// Private function to sum the outstanding minter allowances.
+    fn outstanding_minter_allowances(&self) -> Balance {
+        self.minter_allowed
+            .keys()
+            .map(|minter_id| self.outstanding_minter_allowance(minter_id))
+            .fold(0, Balance::saturating_add)
+    }
+
+    /// Private function to retrieve a minter's outstanding allowance. A rate-limited allowance
+    /// counts as the most it can replenish to.
+    /// * `minter_id`   - The minter's address.
+    fn outstanding_minter_allowance(&self, minter_id: &AccountId) -> Balance {
+        let minter_allowance: Balance = self.minter_allowance(minter_id).0;
+        match self.minter_rate_limits.get(minter_id) {
+            Some(rate_limit) => minter_allowance.max(rate_limit.max_allowance),
+            None => minter_allowance,
+        }
+    }
+
+    /// Private function to apply the [`SupplyCapPolicy`] once a minter allowance has been configured
+    /// or increased, if the outstanding minter allowances plus the total supply exceed the max
+    /// total supply.
+    fn apply_supply_cap_policy(&self) {
+        let max_total_supply: Balance = match (self.max_total_supply, self.supply_cap_policy) {
+            (Some(max_total_supply), SupplyCapPolicy::Warn | SupplyCapPolicy::Fail) => {
+                max_total_supply
+            }
+            _ => return,
+        };
+        let minter_allowances: Balance = self.outstanding_minter_allowances();
+        let total_supply: Balance = self.token.total_supply;
+        if minter_allowances.saturating_add(total_supply) <= max_total_supply {
+            return;
+        }
+        require!(
+            self.supply_cap_policy != SupplyCapPolicy::Fail,
+            "FiatToken: minter allowances exceed max total supply"
+        );
+        fiat_token_event::MinterAllowancesExceedMaxTotalSupply {
+            minter_allowances: U128::from(minter_allowances),
+            total_supply: U128::from(total_supply),
+            max_total_supply: U128::from(max_total_supply),
+        }
+        .emit();
     }
 
     /// Checks if account is a minter.
@@ -200,11 +456,20 @@ impl Contract {
     /// Only callable by a MasterMinter.
     /// * `controller_id`   - The controller to be configured with a minter.
     /// * `minter_id`       - The minter to be set for the newly configured controller.
-    fn configure_controller(&mut self, controller_id: AccountId, minter_id: AccountId) {
+    /// * `expires_at`      - Optional block timestamp after which the Controller Role expires.
+    fn configure_controller(
+        &mut self,
+        controller_id: AccountId,
+        minter_id: AccountId,
+        expires_at: Option<U64>,
+    ) {
         require_only(Role::MasterMinter);
         require_not_blocklisted(&controller_id);
         require_not_blocklisted(&minter_id);
+        self.require_no_role_conflict(&minter_id, &Role::Minter);
+        let expires_at: Option<u64> = Self::validate_role_expiry(expires_at);
         self._grant_multisig_role(controller_id.clone(), &Role::Controller);
+        set_role_expires_at(&controller_id, &Role::Controller, expires_at);
 
         self.controllers
             .insert(controller_id.clone(), minter_id.clone());
@@ -235,10 +500,14 @@ impl Contract {
     /// * `minter_allowance`   - Minter's allowance limit.
     fn configure_minter_allowance(&mut self, minter_allowance: U128) {
         let minter_id: AccountId = self.get_minter().clone();
+        self.require_no_role_conflict(&minter_id, &Role::Minter);
         Rbac::add_role(self, minter_id.clone(), &Role::Minter);
-        self.minter_allowed
-            .insert(minter_id.clone(), minter_allowance);
+        let previous_outstanding_allowance: Balance = self.outstanding_minter_allowance(&minter_id);
+        self.set_minter_allowance(minter_id.clone(), minter_allowance);
         require_not_blocklisted(&minter_id);
+        if self.outstanding_minter_allowance(&minter_id) > previous_outstanding_allowance {
+            self.apply_supply_cap_policy();
+        }
         fiat_token_event::MinterConfigured {
             minter_id,
             minter_allowance,
@@ -251,6 +520,7 @@ impl Contract {
         let minter_id: AccountId = self.get_minter().clone();
         Rbac::remove_role(self, &minter_id, &Role::Minter);
         self.minter_allowed.remove(&minter_id);
+        self.minter_rate_limits.remove(&minter_id);
         fiat_token_event::MinterRemoved { minter_id }.emit();
     }
 
@@ -290,11 +560,51 @@ impl Contract {
         self.configure_minter_allowance(U128::from(new_allowance));
     }
 
+    /// Makes a controller's minter's allowance replenish over time, like a token bucket, so that the
+    /// minter can keep a steady issuance while the amount it can mint at once stays bounded.
+    /// * `max_allowance`           - Allowance up to which the minter allowance replenishes.
+    /// * `replenish_per_second`    - Amount by which the minter allowance replenishes every
+    /// second. 0 removes the rate limit, leaving the current allowance fixed.
+    fn configure_minter_rate_limit(&mut self, max_allowance: U128, replenish_per_second: U128) {
+        let minter_id: AccountId = self.get_minter().clone();
+        require_not_blocklisted(&minter_id);
+        require!(
+            self.is_minter(&minter_id),
+            format!("FiatToken: {minter_id} is not a minter")
+        );
+        // Settle the allowance replenished under the previous rate limit, if any.
+        let minter_allowance: U128 = self.minter_allowance(&minter_id);
+        let previous_outstanding_allowance: Balance = self.outstanding_minter_allowance(&minter_id);
+        if replenish_per_second.0 == 0 {
+            self.minter_rate_limits.remove(&minter_id);
+        } else {
+            self.minter_rate_limits.insert(
+                minter_id.clone(),
+                MinterRateLimit {
+                    max_allowance: max_allowance.0,
+                    replenish_per_second: replenish_per_second.0,
+                    updated_at: env::block_timestamp(),
+                },
+            );
+        }
+        self.set_minter_allowance(minter_id.clone(), minter_allowance);
+        if self.outstanding_minter_allowance(&minter_id) > previous_outstanding_allowance {
+            self.apply_supply_cap_policy();
+        }
+        fiat_token_event::MinterRateLimitConfigured {
+            minter_id,
+            max_allowance,
+            replenish_per_second,
+        }
+        .emit();
+    }
+
     /// Sets the spender_id's allowance over the caller (the holder of the tokens being
     /// approved to be spent) to be a given value.
     /// * `spender_id`  - Spender's address.
     /// * `value`       - Allowance amount.
     pub fn approve(&mut self, spender_id: AccountId, value: U128) {
+        self.require_not_paused(PausableOperation::Approve);
         let holder_id: AccountId = env::predecessor_account_id();
         self._approve(holder_id, spender_id, value)
     }
@@ -304,6 +614,7 @@ impl Contract {
     /// * `spender_id`  - Spender's address.
     /// * `increment`   - Amount of increase in allowance.
     pub fn increase_allowance(&mut self, spender_id: AccountId, increment: U128) {
+        self.require_not_paused(PausableOperation::Approve);
         require!(
             increment.0 > 0,
             "FiatToken: allowance increment must be greater than 0"
@@ -325,6 +636,7 @@ impl Contract {
     /// * `spender_id`  - Spender's address.
     /// * `decrement`   - Amount of decrease in allowance.
     pub fn decrease_allowance(&mut self, spender_id: AccountId, decrement: U128) {
+        self.require_not_paused(PausableOperation::Approve);
         require!(
             decrement.0 > 0,
             "FiatToken: allowance decrement must be greater than 0"
@@ -346,7 +658,6 @@ impl Contract {
     /// * `spender_id`  - Spender's address.
     /// * `allowance`   - Allowance amount.
     fn _approve(&mut self, holder_id: AccountId, spender_id: AccountId, allowance: U128) {
-        require!(!self.paused, "FiatToken: paused");
         require_not_blocklisted(&holder_id);
         require_not_blocklisted(&spender_id);
 
@@ -377,11 +688,17 @@ impl Contract {
     /// * `value`   - Transfer amount.
     /// * return true if successful.
     pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: U128) {
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::TransferFrom);
         let caller_id: AccountId = env::predecessor_account_id();
         require_not_blocklisted(&caller_id);
         require_not_blocklisted(&from);
         require_not_blocklisted(&to);
+        self.require_allowlisted(&caller_id);
+        self.require_allowlisted(&from);
+        self.require_allowlisted(&to);
+        self.require_not_blocklisted_by_oracle(&caller_id);
+        self.require_not_blocklisted_by_oracle(&from);
+        self.require_not_blocklisted_by_oracle(&to);
 
         // Calculate what the allowance will be.
         let new_allowance: u128 = self
@@ -399,23 +716,31 @@ impl Contract {
 
     /// Mints tokens via internal_deposit and emits an FtMint event.
     /// Validates that caller is a minter and that neither the caller nor the to account
-    /// are blacklisted.
+    /// are blacklisted, nor, in allowlist mode, missing from the allowlist.
     /// * `to`      - The address that will receive the minted tokens.
     /// * `amount`  -  The amount of tokens to mint. Must be less than or equal
     /// to the minter_allowance of the caller.
     pub fn mint(&mut self, to: AccountId, amount: U128) {
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::Mint);
         require_only(Role::Minter);
         let caller_id: AccountId = env::predecessor_account_id();
         require_not_blocklisted(&caller_id);
         require_not_blocklisted(&to);
+        self.require_allowlisted(&caller_id);
+        self.require_allowlisted(&to);
+        self.require_not_blocklisted_by_oracle(&caller_id);
+        self.require_not_blocklisted_by_oracle(&to);
         require!(amount.0 > 0, "FiatToken: mint amount not greater than 0");
+        if let Some(max_total_supply) = self.max_total_supply {
+            require!(
+                amount.0 <= max_total_supply.saturating_sub(self.token.total_supply),
+                "FiatToken: mint amount exceeds max total supply"
+            );
+        }
 
         // Calculate new minter allowance after minting.
         let new_minter_allowance: u128 = self
-            .minter_allowed
-            .get(&env::predecessor_account_id())
-            .unwrap_or(&U128::from(0))
+            .minter_allowance(&caller_id)
             .0
             .checked_sub(amount.0)
             .unwrap_or_else(|| env::panic_str("FiatToken: mint amount exceeds minter allowance"));
@@ -423,8 +748,7 @@ impl Contract {
         self.token.internal_deposit(&to, Balance::from(amount));
 
         // Decrease the minter allowance.
-        self.minter_allowed
-            .insert(caller_id, U128::from(new_minter_allowance));
+        self.set_minter_allowance(caller_id, U128::from(new_minter_allowance));
 
         near_contract_standards::fungible_token::events::FtMint {
             owner_id: &to,
@@ -439,7 +763,7 @@ impl Contract {
     /// * `amount`  - The amount of tokens to burn. Must be less than or equal
     /// to the minter's account balance.
     pub fn burn(&mut self, amount: U128) {
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::Burn);
         require_only(Role::Minter);
         let caller_id: AccountId = env::predecessor_account_id();
         require_not_blocklisted(&caller_id);
@@ -462,38 +786,498 @@ impl Contract {
         .emit();
     }
 
-    /// Called by the owner to pause; triggers stopped state.
+    /// Called by the owner to pause; triggers stopped state for every [`PausableOperation`].
     fn pause(&mut self) {
         require_only(Role::Pauser);
-        require!(!self.paused, "FiatToken: paused");
+        let all_flags: u8 = PausableOperation::to_flags(&PAUSABLE_OPERATIONS);
+        require!(self.paused_flags != all_flags, "FiatToken: paused");
         require_not_blocklisted(&env::predecessor_account_id());
-        self.paused = true;
-        fiat_token_event::Paused.emit();
+        self._set_pause_flags(all_flags);
     }
 
-    /// Called by the owner to unpause; returns to normal state.
+    /// Called by the owner to unpause; returns to normal state for every [`PausableOperation`].
     fn unpause(&mut self) {
         require_only(Role::Pauser);
-        require!(self.paused, "FiatToken: not paused");
+        require!(self.paused_flags != 0, "FiatToken: not paused");
+        require_not_blocklisted(&env::predecessor_account_id());
+        self._set_pause_flags(0);
+    }
+
+    /// Pauses exactly the given [`PausableOperation`]s, and unpauses the others.
+    /// * `paused_operations`   - The operations to pause.
+    fn set_pause_flags(&mut self, paused_operations: Vec<PausableOperation>) {
+        require_only(Role::Pauser);
         require_not_blocklisted(&env::predecessor_account_id());
-        self.paused = false;
-        fiat_token_event::Unpaused.emit();
+        let new_flags: u8 = PausableOperation::to_flags(&paused_operations);
+        require!(
+            self.paused_flags != new_flags,
+            "FiatToken: pause flags are unchanged"
+        );
+        self._set_pause_flags(new_flags);
+    }
+
+    /// Private function to update the pause flags, emitting the [`PausableOperation`]s that were
+    /// newly paused and unpaused.
+    /// * `new_flags`   - The new pause flags.
+    fn _set_pause_flags(&mut self, new_flags: u8) {
+        let old_flags: u8 = self.paused_flags;
+        self.paused_flags = new_flags;
+        let paused_operations: Vec<PausableOperation> =
+            PausableOperation::from_flags(new_flags & !old_flags);
+        if !paused_operations.is_empty() {
+            fiat_token_event::Paused {
+                operations: paused_operations,
+            }
+            .emit();
+        }
+        let unpaused_operations: Vec<PausableOperation> =
+            PausableOperation::from_flags(old_flags & !new_flags);
+        if !unpaused_operations.is_empty() {
+            fiat_token_event::Unpaused {
+                operations: unpaused_operations,
+            }
+            .emit();
+        }
+    }
+
+    /// Throws if the given class of operations is paused. Guards every state-changing entry point
+    /// that pausing is meant to halt; view methods never call it, so that they keep answering
+    /// while paused.
+    /// * `operation`   - The class of the operation being performed.
+    fn require_not_paused(&self, operation: PausableOperation) {
+        require!(
+            self.paused_flags & operation.flag() == 0,
+            "FiatToken: paused"
+        );
+    }
+
+    /// Throws if allowlist mode is on and the account does not hold the Allowlisted [`Role`].
+    /// * `account_id`  - The account taking part in the operation.
+    fn require_allowlisted(&self, account_id: &AccountId) {
+        if self.allowlist_enabled {
+            require!(
+                <Contract as Rbac>::has_role(account_id, &Role::Allowlisted),
+                format!("FiatToken: {account_id} is not allowlisted")
+            );
+        }
+    }
+
+    /// Switches allowlist mode on or off. While it is on, every party to a transfer, mint or
+    /// storage deposit must hold the Allowlisted [`Role`].
+    /// * `enabled` - Whether allowlist mode should be on.
+    fn set_allowlist_mode(&mut self, enabled: bool) {
+        require_only(Role::Owner);
+        self.allowlist_enabled = enabled;
+        fiat_token_event::AllowlistModeUpdated { enabled }.emit();
+    }
+
+    /// Grants the AllowlistManager [`Role`] to an account.
+    /// * `allowlist_manager_id`    - The account to add as an allowlist manager.
+    fn add_allowlist_manager(&mut self, allowlist_manager_id: AccountId) {
+        require_only(Role::Owner);
+        require!(
+            !<Contract as Rbac>::has_role(&allowlist_manager_id, &Role::AllowlistManager),
+            "FiatToken: account is already an allowlist manager"
+        );
+        self.require_no_role_conflict(&allowlist_manager_id, &Role::AllowlistManager);
+        Rbac::add_role(self, allowlist_manager_id.clone(), &Role::AllowlistManager);
+        fiat_token_event::AllowlistManagerAdded {
+            allowlist_manager_id,
+        }
+        .emit();
+    }
+
+    /// Revokes the AllowlistManager [`Role`] from an account.
+    /// * `allowlist_manager_id`    - The allowlist manager to remove.
+    fn remove_allowlist_manager(&mut self, allowlist_manager_id: AccountId) {
+        require_only(Role::Owner);
+        require!(
+            <Contract as Rbac>::has_role(&allowlist_manager_id, &Role::AllowlistManager),
+            "FiatToken: account is not an allowlist manager"
+        );
+        Rbac::remove_role(self, &allowlist_manager_id, &Role::AllowlistManager);
+        fiat_token_event::AllowlistManagerRemoved {
+            allowlist_manager_id,
+        }
+        .emit();
+    }
+
+    /// Adds several accounts to the allowlist at once. The call is atomic: it fails without
+    /// allowlisting any account if one of them is already allowlisted or listed twice.
+    /// * `account_ids` - The accounts to allow, at most [`MAX_ACCOUNT_BATCH_SIZE`].
+    pub fn allowlist_many(&mut self, account_ids: Vec<AccountId>) {
+        require_only(Role::AllowlistManager);
+        Self::validate_account_batch(&account_ids);
+        let allowlist_manager_id: AccountId = env::predecessor_account_id();
+        for account_id in account_ids {
+            require!(
+                !<Contract as Rbac>::has_role(&account_id, &Role::Allowlisted),
+                format!("FiatToken: {account_id} is allowlisted")
+            );
+            Rbac::add_role(self, account_id.clone(), &Role::Allowlisted);
+            fiat_token_event::Allowlist {
+                account_id,
+                allowlist_manager_id: allowlist_manager_id.clone(),
+            }
+            .emit();
+        }
+    }
+
+    /// Removes several accounts from the allowlist at once. The call is atomic: it fails without
+    /// unallowlisting any account if one of them is not allowlisted or listed twice.
+    /// * `account_ids` - The accounts to disallow, at most [`MAX_ACCOUNT_BATCH_SIZE`].
+    pub fn unallowlist_many(&mut self, account_ids: Vec<AccountId>) {
+        require_only(Role::AllowlistManager);
+        Self::validate_account_batch(&account_ids);
+        let allowlist_manager_id: AccountId = env::predecessor_account_id();
+        for account_id in account_ids {
+            require!(
+                <Contract as Rbac>::has_role(&account_id, &Role::Allowlisted),
+                format!("FiatToken: {account_id} is not allowlisted")
+            );
+            Rbac::remove_role(self, &account_id, &Role::Allowlisted);
+            fiat_token_event::Unallowlist {
+                account_id,
+                allowlist_manager_id: allowlist_manager_id.clone(),
+            }
+            .emit();
+        }
+    }
+
+    /// Throws if either party to a transfer is blocklisted, locally or by the compliance oracle, or,
+    /// in allowlist mode, is not allowlisted.
+    /// * `sender_id`   - The account sending the tokens.
+    /// * `receiver_id` - The account receiving the tokens.
+    fn require_can_transfer(&self, sender_id: &AccountId, receiver_id: &AccountId) {
+        require_not_blocklisted(sender_id);
+        require_not_blocklisted(receiver_id);
+        self.require_allowlisted(sender_id);
+        self.require_allowlisted(receiver_id);
+        self.require_not_blocklisted_by_oracle(sender_id);
+        self.require_not_blocklisted_by_oracle(receiver_id);
+    }
+
+    /// Throws if an account cannot receive tokens: if it is blocklisted, locally or by the
+    /// compliance oracle, or, in allowlist mode, is not allowlisted.
+    /// * `receiver_id` - The account receiving the tokens.
+    fn require_can_receive(&self, receiver_id: &AccountId) {
+        require_not_blocklisted(receiver_id);
+        self.require_allowlisted(receiver_id);
+        self.require_not_blocklisted_by_oracle(receiver_id);
+    }
+
+    /// Sets the compliance oracle consulted in addition to the local blocklist, or stops consulting
+    /// one. Accounts mirrored from a previous oracle no longer apply.
+    /// * `compliance_oracle`   - The oracle contract, or `None` to stop consulting one.
+    fn update_compliance_oracle(&mut self, compliance_oracle: Option<AccountId>) {
+        require_only(Role::Owner);
+        self.compliance_oracle = compliance_oracle.clone();
+        fiat_token_event::ComplianceOracleUpdated { compliance_oracle }.emit();
+    }
+
+    /// Throws if the account is blocklisted by the compliance oracle, according to the local mirror
+    /// kept up to date through [`Contract::sync_blocklist_from_oracle`].
+    /// * `account_id`  - The account taking part in the operation.
+    fn require_not_blocklisted_by_oracle(&self, account_id: &AccountId) {
+        require!(
+            !self.is_blocklisted_by_oracle(account_id.clone()),
+            format!("FiatToken: {account_id} is blocklisted by the compliance oracle")
+        );
     }
 
-    /// Adds an account to the blocklist.
+    /// Queries the compliance oracle for whether the given accounts are blocklisted, and mirrors its
+    /// answer locally so that it applies to transfers, mints and storage deposits. Callable by
+    /// anyone, e.g. by a keeper following the oracle's updates.
+    /// * `account_ids` - The accounts to sync, at most [`MAX_ACCOUNT_BATCH_SIZE`].
+    pub fn sync_blocklist_from_oracle(&mut self, account_ids: Vec<AccountId>) -> Promise {
+        Self::validate_account_batch(&account_ids);
+        let compliance_oracle: AccountId = self
+            .compliance_oracle
+            .clone()
+            .unwrap_or_else(|| env::panic_str("FiatToken: no compliance oracle is configured"));
+        ext_compliance_oracle::ext(compliance_oracle.clone())
+            .with_static_gas(GAS_FOR_COMPLIANCE_ORACLE_CALL)
+            .are_blocklisted(account_ids.clone())
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_ORACLE_BLOCKLIST_SYNC)
+                    .on_oracle_blocklist_synced(compliance_oracle, account_ids),
+            )
+    }
+
+    /// Callback of [`Contract::sync_blocklist_from_oracle`], mirroring the oracle's answer.
+    /// * `compliance_oracle`   - The oracle that was queried.
+    /// * `account_ids`         - The accounts that were synced.
+    /// * `blocklisted`         - The oracle's answer, telling for each account whether it is
+    /// blocklisted.
+    #[private]
+    pub fn on_oracle_blocklist_synced(
+        &mut self,
+        compliance_oracle: AccountId,
+        account_ids: Vec<AccountId>,
+        #[callback_result] blocklisted: Result<Vec<bool>, PromiseError>,
+    ) {
+        let blocklisted: Vec<bool> = Self::compliance_oracle_answer(&account_ids, blocklisted);
+        for (account_id, blocklisted) in account_ids.into_iter().zip(blocklisted) {
+            let key: (AccountId, AccountId) = (compliance_oracle.clone(), account_id.clone());
+            if self.oracle_blocklist.contains(&key) == blocklisted {
+                continue;
+            }
+            if blocklisted {
+                self.oracle_blocklist.insert(key);
+            } else {
+                self.oracle_blocklist.remove(&key);
+            }
+            fiat_token_event::OracleBlocklistSynced {
+                compliance_oracle: compliance_oracle.clone(),
+                account_id,
+                blocklisted,
+            }
+            .emit();
+        }
+    }
+
+    /// Callback of [`FungibleTokenCore::ft_transfer_call`] when a compliance oracle is configured,
+    /// performing the transfer once the oracle has confirmed that neither party is blocklisted.
+    /// * `sender_id`   - The account sending the tokens.
+    /// * `receiver_id` - The account receiving the tokens.
+    /// * `amount`      - The amount of tokens to transfer.
+    /// * `memo`        - Optional memo of the transfer.
+    /// * `msg`         - The message passed to the receiver's `ft_on_transfer`.
+    /// * `blocklisted` - The oracle's answer, telling whether the sender and the receiver are
+    /// blocklisted.
+    #[private]
+    pub fn on_ft_transfer_call_screened(
+        &mut self,
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+        msg: String,
+        #[callback_result] blocklisted: Result<Vec<bool>, PromiseError>,
+    ) -> PromiseOrValue<U128> {
+        let parties: [AccountId; 2] = [sender_id.clone(), receiver_id.clone()];
+        let blocklisted: Vec<bool> = Self::compliance_oracle_answer(&parties, blocklisted);
+        for (account_id, blocklisted) in parties.iter().zip(blocklisted) {
+            require!(
+                !blocklisted,
+                format!("FiatToken: {account_id} is blocklisted by the compliance oracle")
+            );
+        }
+        // The contract may have been paused, or the parties blocklisted, while the oracle was
+        // being consulted.
+        self.require_not_paused(PausableOperation::TransferCall);
+        self.require_can_transfer(&sender_id, &receiver_id);
+
+        self.token
+            .internal_transfer(&sender_id, &receiver_id, amount.0, memo);
+        let receiver_gas: u64 = env::prepaid_gas()
+            .0
+            .checked_sub(GAS_FOR_FT_TRANSFER_CALL.0)
+            .unwrap_or_else(|| env::panic_str("Prepaid gas overflow"));
+        ext_ft_receiver::ext(receiver_id.clone())
+            .with_static_gas(Gas(receiver_gas))
+            .ft_on_transfer(sender_id.clone(), amount, msg)
+            .then(
+                ext_ft_resolver::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_RESOLVE_TRANSFER)
+                    .ft_resolve_transfer(sender_id, receiver_id, amount),
+            )
+            .into()
+    }
+
+    /// Private function to unwrap the compliance oracle's answer for the given accounts, throwing
+    /// if the oracle could not be reached or did not answer for every account.
+    /// * `account_ids` - The accounts the oracle was queried for.
+    /// * `answer`      - The result of the oracle call.
+    fn compliance_oracle_answer(
+        account_ids: &[AccountId],
+        answer: Result<Vec<bool>, PromiseError>,
+    ) -> Vec<bool> {
+        let blocklisted: Vec<bool> =
+            answer.unwrap_or_else(|_| env::panic_str("FiatToken: compliance oracle call failed"));
+        require!(
+            blocklisted.len() == account_ids.len(),
+            "FiatToken: invalid compliance oracle response"
+        );
+        blocklisted
+    }
+
+    /// Adds an account to the blocklist, recording why, when and by whom it was blocklisted. Fails
+    /// if the account is already blocklisted.
     /// * `account_id`  - The account to block.
-    pub fn blocklist(&mut self, account_id: AccountId) {
+    /// * `reason`      - Optional reason for blocklisting the account.
+    /// * `reference`   - Optional context for the reason, e.g. a case number. At most
+    /// [`MAX_BLOCKLIST_REFERENCE_LENGTH`] bytes.
+    pub fn blocklist(
+        &mut self,
+        account_id: AccountId,
+        reason: Option<BlocklistReason>,
+        reference: Option<String>,
+    ) {
         require_only(Role::Blocklister);
-        Rbac::add_role(self, account_id.clone(), &Role::Blocklisted);
-        fiat_token_event::Blocklist { account_id }.emit();
+        let entry: BlocklistEntry = Self::new_blocklist_entry(reason, reference);
+        self._blocklist(account_id, entry);
     }
 
     /// Removes an account from the blocklist.
     /// * `account_id`  - The account to unblock.
     pub fn unblocklist(&mut self, account_id: AccountId) {
         require_only(Role::Blocklister);
+        self._unblocklist(account_id);
+    }
+
+    /// Adds several accounts to the blocklist at once, for the same reason. The call is atomic: it
+    /// fails without blocklisting any account if one of them is already blocklisted or listed
+    /// twice.
+    /// * `account_ids` - The accounts to block, at most [`MAX_ACCOUNT_BATCH_SIZE`].
+    /// * `reason`      - Optional reason for blocklisting the accounts.
+    /// * `reference`   - Optional context for the reason, e.g. a case number. At most
+    /// [`MAX_BLOCKLIST_REFERENCE_LENGTH`] bytes.
+    pub fn blocklist_many(
+        &mut self,
+        account_ids: Vec<AccountId>,
+        reason: Option<BlocklistReason>,
+        reference: Option<String>,
+    ) {
+        require_only(Role::Blocklister);
+        Self::validate_account_batch(&account_ids);
+        let entry: BlocklistEntry = Self::new_blocklist_entry(reason, reference);
+        for account_id in account_ids {
+            self._blocklist(account_id, entry.clone());
+        }
+    }
+
+    /// Removes several accounts from the blocklist at once. The call is atomic: it fails without
+    /// unblocklisting any account if one of them is not blocklisted or listed twice.
+    /// * `account_ids` - The accounts to unblock, at most [`MAX_ACCOUNT_BATCH_SIZE`].
+    pub fn unblocklist_many(&mut self, account_ids: Vec<AccountId>) {
+        require_only(Role::Blocklister);
+        Self::validate_account_batch(&account_ids);
+        for account_id in account_ids {
+            require!(
+                <Contract as Rbac>::has_role(&account_id, &Role::Blocklisted),
+                format!("FiatToken: {account_id} is not blocklisted")
+            );
+            self._unblocklist(account_id);
+        }
+    }
+
+    /// Private function to verify that a batch of accounts to blocklist, unblocklist, allowlist or
+    /// unallowlist is neither
+    /// empty nor larger than [`MAX_ACCOUNT_BATCH_SIZE`], and does not list an account twice.
+    /// * `account_ids` - The accounts of the batch.
+    fn validate_account_batch(account_ids: &[AccountId]) {
+        require!(
+            !account_ids.is_empty() && account_ids.len() <= MAX_ACCOUNT_BATCH_SIZE,
+            format!(
+                "FiatToken: batch must contain between 1 and {MAX_ACCOUNT_BATCH_SIZE} accounts"
+            )
+        );
+        let mut sorted_account_ids: Vec<&AccountId> = account_ids.iter().collect();
+        sorted_account_ids.sort();
+        sorted_account_ids.dedup();
+        require!(
+            sorted_account_ids.len() == account_ids.len(),
+            "FiatToken: batch contains duplicate accounts"
+        );
+    }
+
+    /// Burns the funds of a blocklisted account, e.g. as required by a law-enforcement order, or
+    /// moves them to a custody account so that they can be reissued.
+    /// * `account_id`  - The blocklisted account to wipe the funds of.
+    /// * `destination` - Optional custody account to move the funds to instead of burning them.
+    /// Must be registered and not blocklisted.
+    fn wipe_blocklisted_funds(&mut self, account_id: AccountId, destination: Option<AccountId>) {
+        require_only(Role::Owner);
+        require!(
+            <Contract as Rbac>::has_role(&account_id, &Role::Blocklisted),
+            format!("FiatToken: {account_id} is not blocklisted")
+        );
+        let amount: Balance = self.token.ft_balance_of(account_id.clone()).0;
+        require!(amount > 0, format!("FiatToken: {account_id} has no funds"));
+
+        let memo: String = format!("Wiped funds of blocklisted account {account_id}");
+        match &destination {
+            Some(destination) => {
+                self.require_can_receive(destination);
+                self.token
+                    .internal_transfer(&account_id, destination, amount, Some(memo));
+            }
+            None => {
+                self.token.internal_withdraw(&account_id, amount);
+                near_contract_standards::fungible_token::events::FtBurn {
+                    owner_id: &account_id,
+                    amount: &U128::from(amount),
+                    memo: Some(&memo),
+                }
+                .emit();
+            }
+        }
+        fiat_token_event::BlocklistedFundsWiped {
+            account_id,
+            destination,
+            amount: U128::from(amount),
+        }
+        .emit();
+    }
+
+    /// Retrieves why, when and by whom an account was blocklisted. Returns `None` if the account is
+    /// not blocklisted, or was blocklisted before these records were kept.
+    /// * `account_id`  - The blocklisted account.
+    pub fn blocklist_entry(&self, account_id: AccountId) -> Option<BlocklistEntry> {
+        self.blocklist_entries.get(&account_id).cloned()
+    }
+
+    /// Private function to build the [`BlocklistEntry`] of accounts blocklisted by the caller.
+    /// * `reason`      - Optional reason for blocklisting the accounts.
+    /// * `reference`   - Optional context for the reason.
+    fn new_blocklist_entry(
+        reason: Option<BlocklistReason>,
+        reference: Option<String>,
+    ) -> BlocklistEntry {
+        let entry: BlocklistEntry = BlocklistEntry {
+            reason,
+            reference,
+            blocklister_id: env::predecessor_account_id(),
+            blocklisted_at: U64::from(env::block_timestamp()),
+        };
+        entry.validate();
+        entry
+    }
+
+    /// Private function to add an account to the blocklist. Fails if the account is already
+    /// blocklisted, so that its original [`BlocklistEntry`] is kept.
+    /// * `account_id`  - The account to block.
+    /// * `entry`       - Why, when and by whom the account is blocklisted.
+    fn _blocklist(&mut self, account_id: AccountId, entry: BlocklistEntry) {
+        require!(
+            !<Contract as Rbac>::has_role(&account_id, &Role::Blocklisted),
+            format!("FiatToken: {account_id} is blocklisted")
+        );
+        Rbac::add_role(self, account_id.clone(), &Role::Blocklisted);
+        self.blocklist_entries
+            .insert(account_id.clone(), entry.clone());
+        fiat_token_event::Blocklist {
+            account_id,
+            blocklister_id: entry.blocklister_id,
+            reason: entry.reason,
+            reference: entry.reference,
+            blocklisted_at: entry.blocklisted_at,
+        }
+        .emit();
+    }
+
+    /// Private function to remove an account from the blocklist.
+    /// * `account_id`  - The account to unblock.
+    fn _unblocklist(&mut self, account_id: AccountId) {
         Rbac::remove_role(self, &account_id, &Role::Blocklisted);
-        fiat_token_event::Unblocklist { account_id }.emit();
+        self.blocklist_entries.remove(&account_id);
+        fiat_token_event::Unblocklist {
+            account_id,
+            blocklister_id: env::predecessor_account_id(),
+        }
+        .emit();
     }
 
     /// Retrieves the current admins of this contract.
@@ -501,9 +1285,29 @@ impl Contract {
         <Contract as Rbac>::iter_members_of(&Role::Admin).collect()
     }
 
-    /// Retrieves the current blocklister.
+    /// Retrieves a page of the current admins of this contract, see [`Contract::role_members`].
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn list_admins(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<AccountId> {
+        self.role_members(&Role::Admin, from_index, limit)
+    }
+
+    /// Deprecated, use [`Contract::blocklisters`] instead. Kept for backward compatibility with
+    /// the single blocklister of previous versions: as there can now be several blocklisters, this
+    /// retrieves the one with the lowest account ID.
     pub fn blocklister(&self) -> AccountId {
-        self.blocklister.clone()
+        <Contract as Rbac>::iter_members_of(&Role::Blocklister)
+            .min()
+            .unwrap_or_else(|| env::panic_str("FiatToken: there is no blocklister"))
+    }
+
+    /// Retrieves the current blocklisters.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn blocklisters(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<AccountId> {
+        self.role_members(&Role::Blocklister, from_index, limit)
     }
 
     /// Retrieves the current master minters.
@@ -511,21 +1315,208 @@ impl Contract {
         <Contract as Rbac>::iter_members_of(&Role::MasterMinter).collect()
     }
 
+    /// Retrieves a page of the current master minters, see [`Contract::role_members`].
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn list_master_minters(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AccountId> {
+        self.role_members(&Role::MasterMinter, from_index, limit)
+    }
+
     /// Retrieves the current owners of this contract.
     pub fn owners(&self) -> Vec<AccountId> {
         <Contract as Rbac>::iter_members_of(&Role::Owner).collect()
     }
 
+    /// Retrieves a page of the current owners of this contract, see [`Contract::role_members`].
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn list_owners(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<AccountId> {
+        self.role_members(&Role::Owner, from_index, limit)
+    }
+
     /// Retrieves the current pausers.
     pub fn pausers(&self) -> Vec<AccountId> {
         <Contract as Rbac>::iter_members_of(&Role::Pauser).collect()
     }
 
+    /// Retrieves a page of the current pausers, see [`Contract::role_members`].
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn list_pausers(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<AccountId> {
+        self.role_members(&Role::Pauser, from_index, limit)
+    }
+
+    /// Retrieves the current members of a [`Role`].
+    /// * `role`        - The Role to list the members of.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn role_members(
+        &self,
+        role: &Role,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AccountId> {
+        <Contract as Rbac>::iter_members_of(role)
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(Self::page_size(limit))
+            .collect()
+    }
+
+    /// Returns the number of accounts holding the given [`Role`].
+    /// * `role`    - The Role to count the members of.
+    pub fn role_member_count(&self, role: &Role) -> u32 {
+        <Contract as Rbac>::with_members_of(role, |members| members.len())
+    }
+
+    /// Retrieves every [`Role`] held by an account.
+    /// * `account_id`  - The account to list the Roles of.
+    pub fn account_roles(&self, account_id: &AccountId) -> Vec<Role> {
+        ROLES
+            .into_iter()
+            .filter(|role| <Contract as Rbac>::has_role(account_id, role))
+            .collect()
+    }
+
     /// Returns whether or not a specific account_id is blocklisted.
     pub fn is_blocklisted(&self, account_id: AccountId) -> bool {
         <Contract as Rbac>::has_role(&account_id, &Role::Blocklisted)
     }
 
+    /// Returns whether or not a specific account_id is allowlisted.
+    pub fn is_allowlisted(&self, account_id: AccountId) -> bool {
+        <Contract as Rbac>::has_role(&account_id, &Role::Allowlisted)
+    }
+
+    /// Returns the compliance oracle consulted in addition to the local blocklist, if any.
+    pub fn compliance_oracle(&self) -> Option<AccountId> {
+        self.compliance_oracle.clone()
+    }
+
+    /// Returns whether or not the configured compliance oracle blocklisted a specific account_id,
+    /// as of its last sync through [`Contract::sync_blocklist_from_oracle`].
+    pub fn is_blocklisted_by_oracle(&self, account_id: AccountId) -> bool {
+        match &self.compliance_oracle {
+            Some(compliance_oracle) => self
+                .oracle_blocklist
+                .contains(&(compliance_oracle.clone(), account_id)),
+            None => false,
+        }
+    }
+
+    /// Returns whether or not allowlist mode is on.
+    pub fn is_allowlist_enabled(&self) -> bool {
+        self.allowlist_enabled
+    }
+
+    /// Returns whether or not any [`PausableOperation`] of the contract is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_flags != 0
+    }
+
+    /// Retrieves the [`PausableOperation`]s that are currently paused.
+    pub fn paused_operations(&self) -> Vec<PausableOperation> {
+        PausableOperation::from_flags(self.paused_flags)
+    }
+
+    /// Returns whether or not the contract has been approved for upgrade.
+    pub fn is_approved_for_upgrade(&self) -> bool {
+        self.approved_for_upgrade
+    }
+
+    /// Retrieves a summary of the contract's state for monitoring: its version, pause and upgrade
+    /// approval state, and multi-sig settings.
+    pub fn contract_status(&self) -> ContractStatusView {
+        ContractStatusView {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            paused_operations: self.paused_operations(),
+            approved_for_upgrade: self.approved_for_upgrade,
+            allowlist_enabled: self.allowlist_enabled,
+            multisig_configurations: self.get_multisig_configurations(),
+            multisig_timelock_nanoseconds: self.get_multisig_timelock(),
+        }
+    }
+
+    /// Lists the configured controllers along with the minter each of them controls.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of entries to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn controllers(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(AccountId, AccountId)> {
+        self.controllers
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(Self::page_size(limit))
+            .map(|(controller_id, minter_id)| (controller_id.clone(), minter_id.clone()))
+            .collect()
+    }
+
+    /// Lists the configured minters along with their minter allowance.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of entries to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn minters(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<(AccountId, U128)> {
+        self.minter_allowed
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(Self::page_size(limit))
+            .map(|(minter_id, _)| (minter_id.clone(), self.minter_allowance(minter_id)))
+            .collect()
+    }
+
+    /// Lists the blocklisted accounts.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn blocklisted_accounts(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AccountId> {
+        self.role_members(&Role::Blocklisted, from_index, limit)
+    }
+
+    /// Lists the allowlisted accounts.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn allowlisted_accounts(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AccountId> {
+        self.role_members(&Role::Allowlisted, from_index, limit)
+    }
+
+    /// Lists the allowlist managers.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of accounts to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn allowlist_managers(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AccountId> {
+        self.role_members(&Role::AllowlistManager, from_index, limit)
+    }
+
+    /// Returns the number of entries a paginated view function returns, given the requested limit.
+    /// * `limit`   - Requested maximum number of entries. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    fn page_size(limit: Option<u32>) -> usize {
+        limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE) as usize
+    }
+
     /// Retrieves the minter mapped to the controller.
     /// Panics if the controller does not control a minter.
     pub fn get_minter(&self) -> &AccountId {
@@ -538,45 +1529,236 @@ impl Contract {
             .unwrap_or_else(|| env::panic_str("FiatToken: caller does not control a minter"))
     }
 
-    /// Configures a new multi-sig contract role, e.g. Admin, Master Minter, etc.
+    /// Offers a new multi-sig contract role, e.g. Admin, Master Minter, etc. to an account. The
+    /// Role is only granted once the account calls [`accept_role`] within
+    /// [`ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS`], so that it cannot be granted to an account
+    /// nobody controls. Offering a Role again renews the offer.
     /// * `role`        - The contract role to configure the account for.
     /// * `account_id`  - The account for which to grant the roles to.
-    fn configure_multisig_role(&mut self, role: Role, account_id: AccountId) {
+    /// * `expires_at`  - Optional block timestamp after which the granted Role expires.
+    fn configure_multisig_role(
+        &mut self,
+        role: Role,
+        account_id: AccountId,
+        expires_at: Option<U64>,
+    ) {
         match role {
             Role::Admin => require_only(Role::Admin),
             Role::MasterMinter | Role::Owner | Role::Pauser => require_only(Role::Owner),
             _ => env::panic_str("FiatToken: cannot grant the specified role"),
         };
+        self.require_no_role_conflict(&account_id, &role);
+        let offer: RoleOffer = RoleOffer {
+            expires_at: env::block_timestamp()
+                .saturating_add(ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS),
+            role_expires_at: Self::validate_role_expiry(expires_at),
+        };
+
+        fiat_token_event::RoleOffered {
+            role: role.clone(),
+            account_id: account_id.clone(),
+            expires_at: U64::from(offer.expires_at),
+            role_expires_at: expires_at,
+        }
+        .emit();
+        self.role_offers.insert((account_id, role), offer);
+    }
+
+    /// Accepts a multi-sig contract role offered to the caller through [`configure_multisig_role`],
+    /// granting it the Role.
+    /// * `role`    - The offered contract role to accept.
+    pub fn accept_role(&mut self, role: Role) {
+        let account_id: AccountId = env::predecessor_account_id();
+        let offer_key: (AccountId, Role) = (account_id.clone(), role.clone());
+        let offer: RoleOffer = self.role_offers.remove(&offer_key).unwrap_or_else(|| {
+            env::panic_str("FiatToken: role has not been offered to the caller")
+        });
+        require!(
+            env::block_timestamp() < offer.expires_at,
+            "FiatToken: role offer has expired"
+        );
         self._grant_multisig_role(account_id.clone(), &role);
+        set_role_expires_at(&account_id, &role, offer.role_expires_at);
 
+        fiat_token_event::RoleOfferAccepted {
+            role: role.clone(),
+            account_id: account_id.clone(),
+        }
+        .emit();
         fiat_token_event::RoleConfigured { role, account_id }.emit()
     }
 
-    /// Revokes a multi-sig contract role from an account.
-    /// * `role`        - The contract role to revoke from the account.
-    /// * `account_id`  - The account for which to revoke the roles from.
+    /// Removes a role offer that has not been accepted before its expiry. Callable by anyone.
+    /// * `role`        - The offered contract role.
+    /// * `account_id`  - The account the Role was offered to.
+    pub fn remove_expired_role_offer(&mut self, role: Role, account_id: AccountId) {
+        let offer_key: (AccountId, Role) = (account_id.clone(), role.clone());
+        let offer: RoleOffer = self.role_offers.remove(&offer_key).unwrap_or_else(|| {
+            env::panic_str("FiatToken: role has not been offered to the account")
+        });
+        require!(
+            env::block_timestamp() >= offer.expires_at,
+            "FiatToken: role offer has not expired"
+        );
+
+        fiat_token_event::RoleOfferExpired {
+            role,
+            account_id,
+            expired_at: U64::from(offer.expires_at),
+        }
+        .emit()
+    }
+
+    /// Lists the multi-sig contract roles that have been offered to accounts and not accepted yet,
+    /// including expired offers that have not been removed.
+    /// * `from_index`  - Index to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of offers to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn role_offers(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<RoleOfferView> {
+        self.role_offers
+            .iter()
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(Self::page_size(limit))
+            .map(|((account_id, role), offer)| RoleOfferView {
+                account_id: account_id.clone(),
+                role: role.clone(),
+                expires_at: U64::from(offer.expires_at),
+                is_expired: env::block_timestamp() >= offer.expires_at,
+                role_expires_at: offer.role_expires_at.map(U64::from),
+            })
+            .collect()
+    }
+
+    /// Retrieves the block timestamp after which an account's [`Role`] expires, or `None` if the
+    /// account's grant of the Role is not time-bounded.
+    /// * `account_id`  - The account holding the Role.
+    /// * `role`        - The Role held by the account.
+    pub fn role_expires_at(&self, account_id: AccountId, role: Role) -> Option<U64> {
+        get_role_expires_at(&account_id, &role).map(U64::from)
+    }
+
+    /// Revokes the multi-sig contract roles whose time-bounded grants have expired, unlinking
+    /// expired Controllers from their minters. Grants whose revocation would leave their Role with
+    /// fewer members than its multi-sig threshold are kept until the Role has enough members.
+    /// Callable by anyone.
+    /// Returns the number of revoked grants.
+    /// * `from_index`  - Index, among the members of every multi-sig role, to start scanning
+    /// from. Defaults to 0.
+    /// * `limit`       - Maximum number of members to scan. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn prune_expired_roles(&mut self, from_index: Option<u32>, limit: Option<u32>) -> u32 {
+        let expired_grants: Vec<(AccountId, Role)> = MULTISIG_ROLES
+            .into_iter()
+            .flat_map(|role| {
+                <Contract as Rbac>::iter_members_of(&role)
+                    .map(move |account_id| (account_id, role.clone()))
+            })
+            .skip(from_index.unwrap_or(0) as usize)
+            .take(Self::page_size(limit))
+            .filter(|(account_id, role)| is_role_expired(account_id, role))
+            .collect();
+        let mut pruned_count: u32 = 0;
+        for (account_id, role) in expired_grants {
+            if self.role_member_count(&role) <= self.min_role_member_count(&role) {
+                continue;
+            }
+            self._revoke_multisig_role(&account_id, &role);
+            fiat_token_event::RoleRevoked {
+                role: role.clone(),
+                account_id: account_id.clone(),
+            }
+            .emit();
+            if role == Role::Controller && self.controllers.remove(&account_id).is_some() {
+                fiat_token_event::ControllerRemoved {
+                    controller_id: account_id,
+                }
+                .emit();
+            }
+            pruned_count += 1;
+        }
+        pruned_count
+    }
+
+    /// Private function to verify that the expiry of a time-bounded [`Role`] grant, if any, is in
+    /// the future.
+    /// * `expires_at`  - Optional block timestamp after which the Role expires.
+    fn validate_role_expiry(expires_at: Option<U64>) -> Option<u64> {
+        let expires_at: Option<u64> = expires_at.map(u64::from);
+        if let Some(expires_at) = expires_at {
+            require!(
+                expires_at > env::block_timestamp(),
+                "FiatToken: role expiry must be in the future"
+            );
+        }
+        expires_at
+    }
+
+    /// Revokes a multi-sig contract role from an account, along with any pending offer of the Role
+    /// to the account. The Role must keep at least as many members as its multi-sig threshold, so
+    /// that its requests can still be executed.
+    /// * `role`        - The contract role to revoke from the account.
+    /// * `account_id`  - The account for which to revoke the roles from.
     fn revoke_multisig_role(&mut self, role: Role, account_id: AccountId) {
         match role {
             Role::Admin => require_only(Role::Admin),
             Role::MasterMinter | Role::Owner | Role::Pauser => require_only(Role::Owner),
             _ => env::panic_str("FiatToken: cannot revoke the specified role"),
         };
+        let min_member_count: u32 = self.min_role_member_count(&role);
+        require!(
+            !<Contract as Rbac>::has_role(&account_id, &role)
+                || self.role_member_count(&role) > min_member_count,
+            format!("FiatToken: cannot revoke a {role} below the minimum of {min_member_count} accounts")
+        );
         self._revoke_multisig_role(&account_id, &role);
+        self.role_offers.remove(&(account_id.clone(), role.clone()));
 
         fiat_token_event::RoleRevoked { role, account_id }.emit()
     }
 
-    /// Changes the blocklister to a different account.
+    /// Replaces every blocklister with a single account.
     /// * `new_blocklister_id`  - The account to make the new blocklister.
     fn update_blocklister(&mut self, new_blocklister_id: AccountId) {
         require_only(Role::Owner);
-        let old_blocklister: AccountId = self.blocklister.clone();
-        Rbac::remove_role(self, &old_blocklister, &Role::Blocklister);
+        let old_blocklisters: Vec<AccountId> =
+            <Contract as Rbac>::iter_members_of(&Role::Blocklister).collect();
+        for old_blocklister in old_blocklisters {
+            Rbac::remove_role(self, &old_blocklister, &Role::Blocklister);
+        }
+        self.require_no_role_conflict(&new_blocklister_id, &Role::Blocklister);
         Rbac::add_role(self, new_blocklister_id.clone(), &Role::Blocklister);
-        self.blocklister = new_blocklister_id.clone();
         fiat_token_event::BlocklisterChanged { new_blocklister_id }.emit();
     }
 
+    /// Grants the Blocklister [`Role`] to an account, alongside the current blocklisters.
+    /// * `blocklister_id`  - The account to add as a blocklister.
+    fn add_blocklister(&mut self, blocklister_id: AccountId) {
+        require_only(Role::Owner);
+        require!(
+            !<Contract as Rbac>::has_role(&blocklister_id, &Role::Blocklister),
+            "FiatToken: account is already a blocklister"
+        );
+        self.require_no_role_conflict(&blocklister_id, &Role::Blocklister);
+        Rbac::add_role(self, blocklister_id.clone(), &Role::Blocklister);
+        fiat_token_event::BlocklisterAdded { blocklister_id }.emit();
+    }
+
+    /// Revokes the Blocklister [`Role`] from an account. At least one blocklister must remain.
+    /// * `blocklister_id`  - The blocklister to remove.
+    fn remove_blocklister(&mut self, blocklister_id: AccountId) {
+        require_only(Role::Owner);
+        require!(
+            <Contract as Rbac>::has_role(&blocklister_id, &Role::Blocklister),
+            "FiatToken: account is not a blocklister"
+        );
+        require!(
+            self.role_member_count(&Role::Blocklister) > 1,
+            "FiatToken: cannot remove the last blocklister"
+        );
+        Rbac::remove_role(self, &blocklister_id, &Role::Blocklister);
+        fiat_token_event::BlocklisterRemoved { blocklister_id }.emit();
+    }
+
     /// Approves the contract to be upgraded. While upgrading the contract,
     /// [`approved_for_upgrade`] should be and will be set to false in the [`migrate`] function.
     fn approve_for_upgrade(&mut self) {
@@ -586,6 +1768,182 @@ impl Contract {
         fiat_token_event::ApprovedForUpgrade.emit();
     }
 
+    /// Updates the approval threshold and validity period of multi-signature requests, either for
+    /// the requests requiring a specific multi-sig [`Role`], or the contract-wide default used by
+    /// the Roles that do not have a configuration of their own.
+    /// The threshold must be at least 1 and cannot exceed the number of accounts holding the
+    /// affected Role(s), otherwise their requests could never be executed.
+    /// * `role`                        - The Role to configure, or `None` to update the default.
+    /// * `threshold`                   - Number of approvals required to execute a request.
+    /// * `validity_period_nanoseconds` - Period after which a request can no longer be executed,
+    /// and can be removed. 0 = perpetual validity, no deletion.
+    fn update_multisig_configuration(
+        &mut self,
+        role: Option<Role>,
+        threshold: u8,
+        validity_period_nanoseconds: U64,
+    ) {
+        require_only(Role::Admin);
+        require!(
+            threshold >= 1,
+            "FiatToken: multisig threshold must be at least 1"
+        );
+        let affected_roles: Vec<Role> = match &role {
+            Some(role) => {
+                require!(
+                    MULTISIG_ROLES.contains(role),
+                    "FiatToken: cannot configure multisig for the specified role"
+                );
+                vec![role.clone()]
+            }
+            // Controllers are configured by MasterMinters after deployment, so they are only
+            // checked when their Role is configured explicitly.
+            None => MULTISIG_ROLES
+                .into_iter()
+                .filter(|role| {
+                    *role != Role::Controller && !self.multisig_configurations.contains_key(role)
+                })
+                .collect(),
+        };
+        for affected_role in affected_roles {
+            require!(
+                u32::from(threshold) <= self.role_member_count(&affected_role),
+                format!(
+                    "FiatToken: multisig threshold exceeds the number of {affected_role} accounts"
+                )
+            );
+        }
+
+        let new_config: Configuration<Contract> =
+            Configuration::new(threshold, validity_period_nanoseconds.0);
+        let old_config: Configuration<Contract> = match &role {
+            Some(role) => {
+                let old_config: Configuration<Contract> = self.multisig_configuration(role);
+                self.multisig_configurations
+                    .insert(role.clone(), new_config);
+                old_config
+            }
+            None => <Self as ApprovalManagerInternal<_, _, _>>::slot_config()
+                .swap(&new_config)
+                .unwrap(),
+        };
+        fiat_token_event::MultisigConfigurationUpdated {
+            role,
+            old_threshold: old_config.threshold,
+            new_threshold: threshold,
+            old_validity_period_nanoseconds: U64::from(old_config.validity_period_nanoseconds),
+            new_validity_period_nanoseconds: validity_period_nanoseconds,
+        }
+        .emit();
+    }
+
+    /// Updates the minimum delay between a timelocked multi-signature request reaching its approval
+    /// threshold and being executable. See [`FiatTokenAction::is_timelocked`].
+    /// * `delay_nanoseconds`   - The new delay. 0 = no timelock.
+    fn update_multisig_timelock(&mut self, delay_nanoseconds: U64) {
+        require_only(Role::Admin);
+        let old_delay_nanoseconds: u64 = self.multisig_timelock_nanoseconds;
+        self.multisig_timelock_nanoseconds = delay_nanoseconds.0;
+        fiat_token_event::MultisigTimelockUpdated {
+            old_delay_nanoseconds: U64::from(old_delay_nanoseconds),
+            new_delay_nanoseconds: delay_nanoseconds,
+        }
+        .emit();
+    }
+
+    /// Retrieves the minimum delay between a timelocked multi-signature request reaching its
+    /// approval threshold and being executable.
+    pub fn get_multisig_timelock(&self) -> U64 {
+        U64::from(self.multisig_timelock_nanoseconds)
+    }
+
+    /// Returns the earliest block timestamp at which a multi-signature request can be executed, or
+    /// `None` if the request has not reached its approval threshold yet.
+    /// * `request_id`  - ID of the request.
+    /// * `action`      - The action of the request.
+    fn multisig_executable_at(&self, request_id: u32, action: &FiatTokenAction) -> Option<u64> {
+        let threshold_reached_at: u64 = *self.multisig_threshold_reached_at.get(&request_id)?;
+        Some(match action.is_timelocked() {
+            true => threshold_reached_at.saturating_add(self.multisig_timelock_nanoseconds),
+            false => threshold_reached_at,
+        })
+    }
+
+    /// Returns true if a multi-signature request is held back by its timelock.
+    /// * `request_id`  - ID of the request.
+    /// * `action`      - The action of the request.
+    fn is_multisig_timelocked(&self, request_id: u32, action: &FiatTokenAction) -> bool {
+        action.is_timelocked()
+            && self.multisig_timelock_nanoseconds > 0
+            && match self.multisig_executable_at(request_id, action) {
+                Some(executable_at) => env::block_timestamp() < executable_at,
+                None => true,
+            }
+    }
+
+    /// Retrieves the multi-sig [`Configuration`] applying to requests that require the given
+    /// [`Role`]. Falls back to the contract-wide default if the Role has no configuration of its
+    /// own.
+    /// * `role`    - The Role required by the requests.
+    fn multisig_configuration(&self, role: &Role) -> Configuration<Contract> {
+        match self.multisig_configurations.get(role) {
+            Some(config) => {
+                Configuration::new(config.threshold, config.validity_period_nanoseconds)
+            }
+            None => <Self as ApprovalManager<_, _, _>>::get_config(),
+        }
+    }
+
+    /// Retrieves the multi-sig [`Configuration`] applying to requests for the given action. Actions
+    /// requiring several [`Role`]s (i.e. batches) use the strictest configuration among them: the
+    /// highest threshold and the shortest validity period. Their approvals are however counted
+    /// per Role, see [`Contract::check_multisig_approvals`].
+    /// * `action`  - The action of the requests.
+    fn action_multisig_configuration(&self, action: &FiatTokenAction) -> Configuration<Contract> {
+        let configs: Vec<Configuration<Contract>> = action
+            .roles_required()
+            .iter()
+            .map(|role| self.multisig_configuration(role))
+            .collect();
+        let threshold: u8 = configs.iter().map(|config| config.threshold).max().unwrap();
+        let validity_period_nanoseconds: u64 = configs
+            .iter()
+            .map(|config| config.validity_period_nanoseconds)
+            .filter(|validity_period| *validity_period > 0)
+            .min()
+            .unwrap_or(0);
+        Configuration::new(threshold, validity_period_nanoseconds)
+    }
+
+    /// Retrieves the approval threshold and validity period applying to the requests of each of
+    /// the multi-sig [`Role`]s.
+    pub fn get_multisig_configurations(&self) -> Vec<MultisigConfigurationView> {
+        MULTISIG_ROLES
+            .into_iter()
+            .map(|role| {
+                let config: Configuration<Contract> = self.multisig_configuration(&role);
+                MultisigConfigurationView {
+                    threshold: config.threshold,
+                    validity_period_nanoseconds: U64::from(config.validity_period_nanoseconds),
+                    is_default: !self.multisig_configurations.contains_key(&role),
+                    member_count: self.role_member_count(&role),
+                    min_member_count: self.min_role_member_count(&role),
+                    role,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the minimum number of accounts that must keep holding the given [`Role`], i.e. its
+    /// multi-sig threshold. Controllers are removed along with their minter, and have no minimum.
+    /// * `role`    - The Role to get the minimum member count of.
+    fn min_role_member_count(&self, role: &Role) -> u32 {
+        match role {
+            Role::Controller => 0,
+            _ => u32::from(self.multisig_configuration(role).threshold),
+        }
+    }
+
     /// Helper function for the [`FungibleTokenResolver`] implementation required by
     /// NEP-141.
     fn on_tokens_burned(&mut self, account_id: AccountId, amount: Balance) {
@@ -605,79 +1963,416 @@ impl Contract {
             .unwrap_or(0)
     }
 
+    /// Retrieves a pending multi-signature request, including its decoded [`FiatTokenAction`],
+    /// approvals and validity. Returns `None` if the request does not exist, or has already been
+    /// executed or removed.
+    /// * `request_id`  - ID of the request to retrieve.
+    pub fn get_multisig_request(&self, request_id: u32) -> Option<MultisigRequestView> {
+        <Contract as ApprovalManager<_, _, _>>::get_request(request_id)
+            .map(|request| self.multisig_request_view(request_id, request))
+    }
+
+    /// Lists pending multi-signature requests in ascending order of request ID. Executed and
+    /// removed requests are skipped, and at most [`MAX_MULTISIG_REQUEST_IDS_SCANNED`] request IDs
+    /// are scanned, so fewer than `limit` requests may be returned even though later ones exist.
+    /// * `from_index`  - Request ID to start listing from. Defaults to 0.
+    /// * `limit`       - Maximum number of requests to return. Defaults to, and is capped at,
+    /// [`MAX_PAGE_SIZE`].
+    pub fn list_multisig_requests(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<MultisigRequestView> {
+        let from_index: u32 = from_index.unwrap_or(0);
+        let to_index: u32 = self
+            .get_next_multisig_request_id()
+            .min(from_index.saturating_add(MAX_MULTISIG_REQUEST_IDS_SCANNED));
+        (from_index..to_index)
+            .filter_map(|request_id| self.get_multisig_request(request_id))
+            .take(Self::page_size(limit))
+            .collect()
+    }
+
+    /// Builds the [`MultisigRequestView`] of a multi-signature request.
+    /// * `request_id`  - ID of the request.
+    /// * `request`     - The request as stored by the [`ApprovalManager`].
+    fn multisig_request_view(
+        &self,
+        request_id: u32,
+        request: ActionRequest<FiatTokenAction, ApprovalState>,
+    ) -> MultisigRequestView {
+        let config: Configuration<Contract> = self.action_multisig_configuration(&request.action);
+        let created_at: u64 = request.approval_state.created_at_nanoseconds;
+        let is_executable: bool = self.check_multisig_approvals(&request).is_ok()
+            && !self.is_multisig_timelocked(request_id, &request.action);
+        let executable_at: Option<u64> = self.multisig_executable_at(request_id, &request.action);
+        let metadata: Option<&MultisigRequestMetadata> =
+            self.multisig_request_metadata.get(&request_id);
+        MultisigRequestView {
+            request_id,
+            role_required: request.action.highest_role_required(),
+            roles_required: request.action.roles_required(),
+            action: request.action,
+            description: metadata.and_then(|metadata| metadata.description.clone()),
+            reference_hash: metadata.and_then(|metadata| metadata.reference_hash.clone()),
+            approved_by: request.approval_state.approved_by,
+            created_at: U64::from(created_at),
+            expires_at: match config.validity_period_nanoseconds {
+                0 => None,
+                validity_period => Some(U64::from(created_at.saturating_add(validity_period))),
+            },
+            executable_at: executable_at.map(U64::from),
+            is_executable,
+        }
+    }
+
     /// Creates a multi-signature request for a [`FiatTokenAction`] that must be approved by at
     /// least the ([`ApprovalManager`]'s) configured [`threshold`] and executed within the
     /// configured [`validity_period_nanoseconds`] amount of time.
-    /// Only an account that has been granted the Multisig [`Role`] and the Role specified
+    /// Only an account that has been granted the Multisig [`Role`] and (one of) the Role(s) specified
     /// by the action can successfully create a request.
-    /// * `action`  - The action that this request will execute when it has been fully approved.
-    /// The type of the action must conform to the defined [`FiatTokenAction`],
+    /// * `action`          - The action that this request will execute when it has been fully
+    /// approved. The type of the action must conform to the defined [`FiatTokenAction`],
     /// otherwise a deserialization error will be thrown.
+    /// * `description`     - Optional context for the signers, e.g. a ticket ID or reason. At most
+    /// [`MAX_DESCRIPTION_LENGTH`] bytes.
+    /// * `reference_hash`  - Optional hash of the off-chain document approving the request. At
+    /// most [`MAX_REFERENCE_HASH_LENGTH`] bytes.
     /// Returns the request ID.
-    pub fn create_multisig_request(&mut self, action: FiatTokenAction) -> u32 {
-        require_only(action.role_required());
+    pub fn create_multisig_request(
+        &mut self,
+        action: FiatTokenAction,
+        description: Option<String>,
+        reference_hash: Option<Base64VecU8>,
+    ) -> u32 {
+        action.validate();
+        let metadata: MultisigRequestMetadata = MultisigRequestMetadata {
+            description,
+            reference_hash,
+        };
+        metadata.validate();
+        require_any(&action.roles_required());
         let request_id =
-            ApprovalManager::create_request(self, action, ApprovalState::new()).unwrap();
-        fiat_token_event::MultisigRequestCreated { request_id }.emit();
+            ApprovalManager::create_request(self, action.clone(), ApprovalState::new()).unwrap();
+        if metadata.description.is_some() || metadata.reference_hash.is_some() {
+            self.multisig_request_metadata
+                .insert(request_id, metadata.clone());
+        }
+        fiat_token_event::MultisigRequestCreated {
+            request_id,
+            creator_id: env::predecessor_account_id(),
+            action,
+            description: metadata.description,
+            reference_hash: metadata.reference_hash,
+        }
+        .emit();
         request_id
     }
 
     /// Approves a multi-signature request. Must be called by an account with the Multisig [`Role`]
-    /// and the [`Role`] specified by the action/request.
+    /// and the [`Role`]s specified by the action/request. Requests can only be approved within
+    /// the validity period configured for that Role.
     /// * `request_id`  - ID of the request to approve.
     pub fn approve_multisig_request(&mut self, request_id: u32) {
-        let request = <Contract as ApprovalManager<_, _, _>>::get_request(request_id).unwrap();
-        require_only(request.action.role_required());
-        if request.action.requires_controller_check() {
-            require!(
-                self.controllers.get(request.action.controller()).unwrap()
-                    == self
-                        .controllers
-                        .get(&env::predecessor_account_id())
+        let mut request_slot = <Self as ApprovalManagerInternal<_, _, _>>::slot_request(request_id);
+        let mut request: ActionRequest<FiatTokenAction, ApprovalState> =
+            request_slot.read().unwrap();
+        self.require_multisig_signer(&request.action, "approve");
+        let config: Configuration<Contract> = self.action_multisig_configuration(&request.action);
+        config
+            .try_approve_with_authorized_account(env::predecessor_account_id(), &mut request)
+            .map_err(ApprovalError::<MultisigAuthorizationError, _>::ApprovalError)
+            .unwrap();
+        request_slot.write(&request);
+        fiat_token_event::MultisigRequestApproved {
+            request_id,
+            account_id: env::predecessor_account_id(),
+            approval_count: request.approval_state.approved_by.len() as u32,
+            action: request.action.clone(),
+        }
+        .emit();
+        self.start_multisig_timelock(request_id, &request);
+    }
+
+    /// Private function to verify that a multi-signature request is within its validity period
+    /// and has enough approvals to be executed. A batch requiring several [`Role`]s needs, for
+    /// each of them, the threshold configured for that Role in approvals from accounts holding
+    /// it, so that it can be signed without any account holding every Role.
+    /// * `request` - The request as stored by the [`ApprovalManager`].
+    fn check_multisig_approvals(
+        &self,
+        request: &ActionRequest<FiatTokenAction, ApprovalState>,
+    ) -> Result<(), ExecutionEligibilityError> {
+        let config: Configuration<Contract> = self.action_multisig_configuration(&request.action);
+        let roles_required: Vec<Role> = request.action.roles_required();
+        if roles_required.len() == 1 {
+            return config.is_approved_for_execution(request);
+        }
+        if !config.is_within_validity_period(&request.approval_state) {
+            return Err(RequestExpiredError.into());
+        }
+        for role in roles_required {
+            let current: usize = request
+                .approval_state
+                .approved_by
+                .iter()
+                .filter(|approver_id| has_active_role(approver_id, &role))
+                .count();
+            let required: usize = self.multisig_configuration(&role).threshold as usize;
+            if current < required {
+                return Err(ExecutionEligibilityError::InsufficientApprovals { current, required });
+            }
+        }
+        Ok(())
+    }
+
+    /// Private function to record when a multi-signature request first meets its approval
+    /// threshold, starting its timelock (if any).
+    /// * `request_id`  - ID of the request.
+    /// * `request`     - The request as stored by the [`ApprovalManager`].
+    fn start_multisig_timelock(
+        &mut self,
+        request_id: u32,
+        request: &ActionRequest<FiatTokenAction, ApprovalState>,
+    ) {
+        if self.check_multisig_approvals(request).is_err()
+            || self.multisig_threshold_reached_at.contains_key(&request_id)
+        {
+            return;
+        }
+        self.multisig_threshold_reached_at
+            .insert(request_id, env::block_timestamp());
+        if request.action.is_timelocked() && self.multisig_timelock_nanoseconds > 0 {
+            fiat_token_event::MultisigTimelockStarted {
+                request_id,
+                executable_at: U64::from(
+                    self.multisig_executable_at(request_id, &request.action)
                         .unwrap(),
-                "FiatToken: can only approve requests to configure the allowance of your own minter"
-            );
+                ),
+            }
+            .emit();
+        }
+    }
+
+    /// Withdraws the caller's approval of a multi-signature request, e.g. after finding out that
+    /// the request was crafted wrongly. The request's approvals are re-checked against the
+    /// threshold when it is executed.
+    /// * `request_id`  - ID of the request to revoke the approval of.
+    pub fn revoke_multisig_approval(&mut self, request_id: u32) {
+        let mut request_slot = <Self as ApprovalManagerInternal<_, _, _>>::slot_request(request_id);
+        let mut request: ActionRequest<FiatTokenAction, ApprovalState> =
+            request_slot.read().unwrap();
+        let account_id: AccountId = env::predecessor_account_id();
+        let approval_count: usize = request.approval_state.approved_by.len();
+        request
+            .approval_state
+            .approved_by
+            .retain(|approver_id| *approver_id != account_id);
+        require!(
+            request.approval_state.approved_by.len() < approval_count,
+            "FiatToken: caller has not approved the request"
+        );
+        request_slot.write(&request);
+        // Falling below the threshold resets the timelock (if any).
+        if self.check_multisig_approvals(&request).is_err() {
+            self.multisig_threshold_reached_at.remove(&request_id);
         }
-        ApprovalManager::approve_request(self, request_id).unwrap();
+        fiat_token_event::MultisigApprovalRevoked {
+            request_id,
+            account_id,
+            approval_count: request.approval_state.approved_by.len() as u32,
+            action: request.action,
+        }
+        .emit();
     }
 
     /// Executes a multi-signature request, performing pre-defined behavior.
-    /// Must be called by an account with the Multisig [`Role`] *and* the [`Role`] specified by the
-    /// action/request, and will only work if the request has had sufficient approvals.
+    /// Must be called by an account with the Multisig [`Role`] *and* the [`Role`]s specified by
+    /// the action/request, and will only work if the request has had sufficient approvals, as
+    /// configured for that Role. Fails if the request is timelocked and its timelock has not elapsed
+    /// yet. The timelock starts when an approval makes the request meet its threshold, so a request
+    /// that meets it without having been approved since, e.g. because the threshold was lowered,
+    /// must be approved again (after revoking an approval if need be) to start it.
     /// * `request_id`  - ID of the request to execute.
     pub fn execute_multisig_request(&mut self, request_id: u32) {
-        let request = <Contract as ApprovalManager<_, _, _>>::get_request(request_id).unwrap();
-        require_only(request.action.role_required());
-        if request.action.requires_controller_check() {
-            require!(
-                self.controllers.get(request.action.controller()).unwrap()
-                    == self
-                        .controllers
-                        .get(&env::predecessor_account_id())
-                        .unwrap(),
-                "FiatToken: can only execute requests to configure the allowance of your own minter"
-            );
+        let mut request_slot = <Self as ApprovalManagerInternal<_, _, _>>::slot_request(request_id);
+        let request: ActionRequest<FiatTokenAction, ApprovalState> = request_slot.read().unwrap();
+        self.require_multisig_signer(&request.action, "execute");
+        self.check_multisig_approvals(&request)
+            .map_err(ExecutionError::<MultisigAuthorizationError, _>::ExecutionEligibility)
+            .unwrap();
+        // Controller actions configure the minter of the Controller executing them.
+        require!(
+            request.action.controllers().is_empty()
+                || has_active_role(&env::predecessor_account_id(), &Role::Controller),
+            "FiatToken: requests configuring a minter must be executed by one of its Controllers"
+        );
+        if self.is_multisig_timelocked(request_id, &request.action) {
+            match self.multisig_executable_at(request_id, &request.action) {
+                Some(executable_at) => env::panic_str(&format!(
+                    "FiatToken: multisig request timelock not elapsed until {executable_at}"
+                )),
+                None => env::panic_str("FiatToken: multisig request timelock not started"),
+            }
         }
-        ApprovalManager::execute_request(self, request_id).unwrap()
+        request_slot.remove();
+        self.clear_multisig_request_state(request_id);
+        with_multisig_execution_roles(request.action.roles_required(), || {
+            request.action.clone().execute(self)
+        });
+        fiat_token_event::MultisigRequestExecuted {
+            request_id,
+            account_id: env::predecessor_account_id(),
+            approval_count: request.approval_state.approved_by.len() as u32,
+            action: request.action,
+        }
+        .emit();
     }
 
     /// Removes a multi-signature request. Must be called by an account with the Multisig [`Role`]
-    /// and the [`Role`] specified by the action/request.
+    /// and the [`Role`]s specified by the action/request, and will only work once the request has
+    /// exceeded the validity period configured for that Role.
     /// * `request_id`  - ID of the request to remove.
     pub fn remove_multisig_request(&mut self, request_id: u32) {
-        let request = <Contract as ApprovalManager<_, _, _>>::get_request(request_id).unwrap();
-        require_only(request.action.role_required());
-        if request.action.requires_controller_check() {
+        let mut request_slot = <Self as ApprovalManagerInternal<_, _, _>>::slot_request(request_id);
+        let request: ActionRequest<FiatTokenAction, ApprovalState> = request_slot.read().unwrap();
+        self.require_multisig_signer(&request.action, "remove");
+        let config: Configuration<Contract> = self.action_multisig_configuration(&request.action);
+        config
+            .is_removable(&request)
+            .map_err(RemovalError::<MultisigAuthorizationError, _>::RemovalNotAllowed)
+            .unwrap();
+        request_slot.remove();
+        self.clear_multisig_request_state(request_id);
+        // Requests can only be removed once they have exceeded their validity period.
+        fiat_token_event::MultisigRequestExpired {
+            request_id,
+            expired_at: U64::from(
+                request.approval_state.created_at_nanoseconds + config.validity_period_nanoseconds,
+            ),
+        }
+        .emit();
+        fiat_token_event::MultisigRequestRemoved {
+            request_id,
+            account_id: env::predecessor_account_id(),
+            approval_count: request.approval_state.approved_by.len() as u32,
+            action: request.action,
+        }
+        .emit();
+    }
+
+    /// Cancels a pending multi-signature request for a timelocked action, e.g. during its timelock
+    /// window. Must be called by an account with the Multisig [`Role`] and the [`Role`]s specified
+    /// by the action/request. See [`FiatTokenAction::is_timelocked`].
+    /// * `request_id`  - ID of the request to cancel.
+    pub fn cancel_multisig_request(&mut self, request_id: u32) {
+        let mut request_slot = <Self as ApprovalManagerInternal<_, _, _>>::slot_request(request_id);
+        let request: ActionRequest<FiatTokenAction, ApprovalState> = request_slot.read().unwrap();
+        self.require_multisig_signer(&request.action, "cancel");
+        require!(
+            request.action.is_timelocked(),
+            "FiatToken: only timelocked multisig requests can be cancelled"
+        );
+        request_slot.remove();
+        self.clear_multisig_request_state(request_id);
+        fiat_token_event::MultisigRequestCancelled {
+            request_id,
+            account_id: env::predecessor_account_id(),
+            approval_count: request.approval_state.approved_by.len() as u32,
+            action: request.action,
+        }
+        .emit();
+    }
+
+    /// Private function to remove the state kept by this contract alongside a multi-signature
+    /// request, once the request itself has been executed, removed or cancelled.
+    /// * `request_id`  - ID of the request.
+    fn clear_multisig_request_state(&mut self, request_id: u32) {
+        self.multisig_threshold_reached_at.remove(&request_id);
+        self.multisig_request_metadata.remove(&request_id);
+    }
+
+    /// Private function to verify that the caller can act on a multi-signature request, i.e. that
+    /// it holds the Multisig [`Role`] and one of the Roles required by the request's action, and
+    /// that Controllers only act on requests concerning their own minter.
+    /// * `action`  - The action of the request.
+    /// * `verb`    - What the caller is attempting to do with the request, used in error messages.
+    fn require_multisig_signer(&self, action: &FiatTokenAction, verb: &str) {
+        require_any(&action.roles_required());
+        // Signers of a batch may act through another of its Roles than Controller.
+        if has_active_role(&env::predecessor_account_id(), &Role::Controller) {
+            for controller_id in action.controllers() {
+                require!(
+                    self.controllers.get(controller_id).unwrap()
+                        == self
+                            .controllers
+                            .get(&env::predecessor_account_id())
+                            .unwrap(),
+                    format!(
+                        "FiatToken: can only {verb} requests to configure the allowance of your own minter"
+                    )
+                );
+            }
+        }
+        require_only(Role::Multisig);
+    }
+
+    /// Replaces the pairs of [`Role`]s that a single account cannot hold at the same time. Only
+    /// future grants are checked against the new pairs.
+    /// * `role_conflicts`  - The pairs of Roles that cannot be held by the same account.
+    fn update_role_conflicts(&mut self, role_conflicts: Vec<(Role, Role)>) {
+        require_only(Role::Admin);
+        self.role_conflicts = Self::validate_role_conflicts(role_conflicts);
+        fiat_token_event::RoleConflictsUpdated {
+            role_conflicts: self.role_conflicts.clone(),
+        }
+        .emit();
+    }
+
+    /// Retrieves the pairs of [`Role`]s that a single account cannot hold at the same time.
+    pub fn role_conflicts(&self) -> Vec<(Role, Role)> {
+        self.role_conflicts.clone()
+    }
+
+    /// Private function to verify and normalize pairs of conflicting [`Role`]s: each pair must
+    /// consist of two different Roles other than the Multisig Role, which is held by every
+    /// multi-sig account. Pairs are ordered and deduplicated.
+    /// * `role_conflicts`  - The pairs of Roles that cannot be held by the same account.
+    fn validate_role_conflicts(role_conflicts: Vec<(Role, Role)>) -> Vec<(Role, Role)> {
+        let mut role_conflicts: Vec<(Role, Role)> = role_conflicts
+            .into_iter()
+            .map(|(role_a, role_b)| {
+                require!(
+                    role_a != role_b && role_a != Role::Multisig && role_b != Role::Multisig,
+                    "FiatToken: invalid role conflict"
+                );
+                match role_a < role_b {
+                    true => (role_a, role_b),
+                    false => (role_b, role_a),
+                }
+            })
+            .collect();
+        role_conflicts.sort();
+        role_conflicts.dedup();
+        role_conflicts
+    }
+
+    /// Private function to verify that granting a [`Role`] to an account does not make it hold
+    /// a pair of conflicting Roles.
+    /// * `account_id`  - ID of the account to be granted the role.
+    /// * `role`        - The Role to be granted.
+    fn require_no_role_conflict(&self, account_id: &AccountId, role: &Role) {
+        for (role_a, role_b) in &self.role_conflicts {
+            let conflicting_role: &Role = match role {
+                role if role == role_a => role_b,
+                role if role == role_b => role_a,
+                _ => continue,
+            };
             require!(
-                self.controllers.get(request.action.controller()).unwrap()
-                    == self
-                        .controllers
-                        .get(&env::predecessor_account_id())
-                        .unwrap(),
-                "FiatToken: can only remove requests to configure the allowance of your own minter"
+                !<Contract as Rbac>::has_role(account_id, conflicting_role),
+                format!("FiatToken: {account_id} cannot be both a {role_a} and a {role_b}")
             );
         }
-        ApprovalManager::remove_request(self, request_id).unwrap()
     }
 
     /// Private function to grant the Multisig [`Role`] and a specified [`Role`] to an account.
@@ -686,17 +2381,25 @@ impl Contract {
     /// * `account_id`  - ID of the account to grant the roles.
     /// * `role`        - Pre-defined [`Role`] to grant to the account.
     fn _grant_multisig_role(&mut self, account_id: AccountId, role: &Role) {
+        self.require_no_role_conflict(&account_id, role);
         Rbac::add_role(self, account_id.clone(), &Role::Multisig);
         Rbac::add_role(self, account_id, role);
     }
 
-    /// Private function to revoke the Multisig [`Role`] and the specified [`Role`] from an account.
+    /// Private function to revoke the specified [`Role`] from an account, along with the Multisig
+    /// [`Role`] unless the account still holds an unexpired grant of another multi-sig role.
     /// Must only be called by a contract admin account.
     /// * `account_id`  - ID of the account to revoke the roles from.
     /// * `role`        - Pre-defined [`Role`] to revoke from the account.
     fn _revoke_multisig_role(&mut self, account_id: &AccountId, role: &Role) {
-        Rbac::remove_role(self, account_id, &Role::Multisig);
         Rbac::remove_role(self, account_id, role);
+        set_role_expires_at(account_id, role, None);
+        if !MULTISIG_ROLES.iter().any(|multisig_role| {
+            <Contract as Rbac>::has_role(account_id, multisig_role)
+                && !is_role_expired(account_id, multisig_role)
+        }) {
+            Rbac::remove_role(self, account_id, &Role::Multisig);
+        }
     }
 
     /// Should only be called by this contract on migration.
@@ -733,9 +2436,28 @@ impl Contract {
             allowed: prev.allowed,
             controllers: prev.controllers,
             minter_allowed: prev.minter_allowed,
-            blocklister: prev.blocklister.clone(),
-            paused: prev.paused,
+            paused_flags: match prev.paused {
+                true => PausableOperation::to_flags(&PAUSABLE_OPERATIONS),
+                false => 0,
+            },
             approved_for_upgrade: false, // Need to reset to false.
+            multisig_configurations: UnorderedMap::new(FiatTokenStorageKey::MultisigConfigurations),
+            multisig_timelock_nanoseconds: 0,
+            multisig_threshold_reached_at: UnorderedMap::new(
+                FiatTokenStorageKey::MultisigThresholdReachedAt,
+            ),
+            multisig_request_metadata: UnorderedMap::new(
+                FiatTokenStorageKey::MultisigRequestMetadata,
+            ),
+            role_conflicts: DEFAULT_ROLE_CONFLICTS.to_vec(),
+            role_offers: UnorderedMap::new(FiatTokenStorageKey::RoleOffers),
+            blocklist_entries: UnorderedMap::new(FiatTokenStorageKey::BlocklistEntries),
+            allowlist_enabled: false,
+            compliance_oracle: None,
+            oracle_blocklist: LookupSet::new(FiatTokenStorageKey::OracleBlocklist),
+            minter_rate_limits: UnorderedMap::new(FiatTokenStorageKey::MinterRateLimits),
+            max_total_supply: None,
+            supply_cap_policy: SupplyCapPolicy::Ignore,
         };
 
         // Re-name token from USD Coin to USDC.
@@ -771,9 +2493,8 @@ impl FungibleTokenMetadataProvider for Contract {
 impl FungibleTokenCore for Contract {
     #[payable]
     fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>) {
-        require!(!self.paused, "FiatToken: paused");
-        require_not_blocklisted(&env::predecessor_account_id());
-        require_not_blocklisted(&receiver_id);
+        self.require_not_paused(PausableOperation::Transfer);
+        self.require_can_transfer(&env::predecessor_account_id(), &receiver_id);
         self.token.ft_transfer(receiver_id, amount, memo);
     }
 
@@ -785,19 +2506,45 @@ impl FungibleTokenCore for Contract {
         memo: Option<String>,
         msg: String,
     ) -> PromiseOrValue<U128> {
-        require!(!self.paused, "FiatToken: paused");
-        require_not_blocklisted(&env::predecessor_account_id());
-        require_not_blocklisted(&receiver_id);
-        self.token.ft_transfer_call(receiver_id, amount, memo, msg)
+        self.require_not_paused(PausableOperation::TransferCall);
+        let sender_id: AccountId = env::predecessor_account_id();
+        self.require_can_transfer(&sender_id, &receiver_id);
+        match self.compliance_oracle.clone() {
+            None => self.token.ft_transfer_call(receiver_id, amount, memo, msg),
+            // The receiver can act on the tokens right away, so rather than trusting the local
+            // mirror, the oracle is consulted before transferring.
+            Some(compliance_oracle) => {
+                near_sdk::assert_one_yocto();
+                require!(
+                    env::prepaid_gas()
+                        > GAS_FOR_COMPLIANCE_ORACLE_CALL + GAS_FOR_SCREENED_FT_TRANSFER_CALL,
+                    "More gas is required"
+                );
+                ext_compliance_oracle::ext(compliance_oracle)
+                    .with_static_gas(GAS_FOR_COMPLIANCE_ORACLE_CALL)
+                    .with_unused_gas_weight(0)
+                    .are_blocklisted(vec![sender_id.clone(), receiver_id.clone()])
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(GAS_FOR_SCREENED_FT_TRANSFER_CALL)
+                            .on_ft_transfer_call_screened(
+                                sender_id,
+                                receiver_id,
+                                amount,
+                                memo,
+                                msg,
+                            ),
+                    )
+                    .into()
+            }
+        }
     }
 
     fn ft_total_supply(&self) -> U128 {
-        require!(!self.paused, "FiatToken: paused");
         self.token.ft_total_supply()
     }
 
     fn ft_balance_of(&self, account_id: AccountId) -> U128 {
-        require!(!self.paused, "FiatToken: paused");
         self.token.ft_balance_of(account_id)
     }
 }
@@ -811,7 +2558,7 @@ impl FungibleTokenResolver for Contract {
         receiver_id: AccountId,
         amount: U128,
     ) -> U128 {
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::TransferCall);
         require_not_blocklisted(&sender_id);
         require_not_blocklisted(&receiver_id);
         let (used_amount, burned_amount) =
@@ -836,10 +2583,14 @@ impl StorageManagement for Contract {
         account_id: Option<AccountId>,
         registration_only: Option<bool>,
     ) -> StorageBalance {
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::Storage);
         require_not_blocklisted(&env::predecessor_account_id());
+        self.require_allowlisted(&env::predecessor_account_id());
+        self.require_not_blocklisted_by_oracle(&env::predecessor_account_id());
         if let Some(account) = account_id.clone() {
             require_not_blocklisted(&account);
+            self.require_allowlisted(&account);
+            self.require_not_blocklisted_by_oracle(&account);
         }
         self.token.storage_deposit(account_id, registration_only)
     }
@@ -852,7 +2603,7 @@ impl StorageManagement for Contract {
     #[payable]
     fn storage_unregister(&mut self, force: Option<bool>) -> bool {
         near_sdk::assert_one_yocto();
-        require!(!self.paused, "FiatToken: paused");
+        self.require_not_paused(PausableOperation::Storage);
         require_not_blocklisted(&env::predecessor_account_id());
         let account_id = env::predecessor_account_id();
         let force = force.unwrap_or(false);
@@ -888,6 +2639,7 @@ mod tests {
     use near_sdk::{test_utils, testing_env, AccountId, Balance, ONE_YOCTO};
 
     use super::*;
+    use crate::blocklist_entry::MAX_BLOCKLIST_REFERENCE_LENGTH;
 
     const USDC_CONTRACT_DECIMALS: u8 = 6;
     const USDC_CONTRACT_SYMBOL: &str = "USDC";
@@ -933,6 +2685,14 @@ mod tests {
         "blocklister".parse().unwrap()
     }
 
+    fn allowlist_manager() -> AccountId {
+        "allowlist_manager".parse().unwrap()
+    }
+
+    fn compliance_oracle() -> AccountId {
+        "compliance_oracle".parse().unwrap()
+    }
+
     fn controller() -> AccountId {
         "controller".parse().unwrap()
     }
@@ -954,11 +2714,12 @@ mod tests {
             pausers,
             blocklister(),
             FungibleTokenMetadata::from(get_metadata()),
+            None,
         );
 
         // By default, configure a controller to control a minter, and configure the minter's allowance to be the max U128 value.
         set_caller(master_minter());
-        usdc.configure_controller(controller(), minter());
+        usdc.configure_controller(controller(), minter(), None);
 
         set_caller(controller());
         usdc.configure_minter_allowance(U128::from(u128::MAX));
@@ -982,7 +2743,36 @@ mod tests {
     // Helper function to blocklist within the testing env context.
     fn _blocklist(contract: &mut Contract, account_id: AccountId) {
         set_caller(blocklister());
-        contract.blocklist(account_id);
+        contract.blocklist(account_id, None, None);
+    }
+
+    // Helper function to allowlist accounts and switch allowlist mode on within the testing env
+    // context.
+    fn enable_allowlist(contract: &mut Contract, account_ids: Vec<AccountId>) {
+        set_caller(owner());
+        contract.add_allowlist_manager(allowlist_manager());
+        set_caller(allowlist_manager());
+        contract.allowlist_many(account_ids);
+        set_caller(owner());
+        contract.set_allowlist_mode(true);
+    }
+
+    // Helper function to configure the compliance oracle and mirror its answer for the given
+    // accounts within the testing env context.
+    fn sync_oracle_blocklist(contract: &mut Contract, account_ids: Vec<AccountId>) {
+        set_caller(owner());
+        contract.update_compliance_oracle(Some(compliance_oracle()));
+        // Callbacks are private, so they are called by the contract itself.
+        set_caller(accounts(0));
+        let blocklisted: Vec<bool> = vec![true; account_ids.len()];
+        contract.on_oracle_blocklist_synced(compliance_oracle(), account_ids, Ok(blocklisted));
+    }
+
+    // Helper function to set the caller and the block timestamp of the current testing env context.
+    fn set_caller_at(caller_id: AccountId, block_timestamp: u64) {
+        let mut context: VMContextBuilder = get_context(caller_id);
+        context.block_timestamp(block_timestamp);
+        testing_env!(context.build());
     }
 
     // Helper function to set the caller of the current testing env context.
@@ -992,6 +2782,14 @@ mod tests {
         context
     }
 
+    fn configure_and_accept_role(usdc: &mut Contract, role: Role, account_id: AccountId) {
+        let caller_id: AccountId = env::predecessor_account_id();
+        usdc.configure_multisig_role(role.clone(), account_id.clone(), None);
+        set_caller(account_id);
+        usdc.accept_role(role);
+        set_caller(caller_id);
+    }
+
     #[test]
     fn test_init() {
         let usdc: Contract = init_contract();
@@ -1010,6 +2808,140 @@ mod tests {
         assert_eq!(metadata.decimals, 6);
     }
 
+    #[test]
+    fn test_role_members() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let admin2: AccountId = "admin2".parse().unwrap();
+        let admin3: AccountId = "admin3".parse().unwrap();
+        set_caller(admin());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin2.clone());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin3.clone());
+
+        // Act & Assert.
+        assert_eq!(usdc.admins(), vec![admin(), admin2.clone(), admin3.clone()]);
+        assert_eq!(usdc.list_admins(Some(1), Some(1)), vec![admin2.clone()]);
+        assert_eq!(
+            usdc.role_members(&Role::Admin, Some(1), None),
+            vec![admin2, admin3]
+        );
+        assert_eq!(usdc.role_member_count(&Role::Admin), 3);
+        assert_eq!(usdc.role_member_count(&Role::Minter), 1);
+        assert_eq!(usdc.role_member_count(&Role::Blocklisted), 0);
+    }
+
+    #[test]
+    fn test_account_roles() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        usdc._grant_multisig_role(admin(), &Role::Controller);
+
+        // Act & Assert.
+        assert_eq!(
+            usdc.account_roles(&admin()),
+            vec![Role::Multisig, Role::Admin, Role::Controller]
+        );
+        assert_eq!(usdc.account_roles(&minter()), vec![Role::Minter]);
+        assert!(usdc.account_roles(&accounts(1)).is_empty());
+    }
+
+    #[test]
+    fn test_role_conflicts_default() {
+        // Arrange.
+        let usdc: Contract = init_contract();
+
+        // Act & Assert.
+        assert_eq!(usdc.role_conflicts(), DEFAULT_ROLE_CONFLICTS.to_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: admin cannot be both a Admin and a Pauser")]
+    fn test_configure_multisig_role_conflicting_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.configure_multisig_role(Role::Pauser, admin(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter cannot be both a Controller and a Minter")]
+    fn test_configure_controller_conflicting_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(master_minter());
+
+        // Act.
+        usdc.configure_controller(minter(), controller(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: admin cannot be both a Admin and a Owner")]
+    fn test_init_conflicting_roles() {
+        // Arrange.
+        testing_env!(VMContextBuilder::new().build());
+
+        // Act.
+        Contract::init(
+            vec![admin()],
+            vec![master_minter()],
+            vec![admin()],
+            vec![pauser()],
+            blocklister(),
+            get_metadata(),
+            None,
+        );
+    }
+
+    #[test]
+    fn test_update_role_conflicts() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_role_conflicts(vec![
+            (Role::Pauser, Role::Admin),
+            (Role::Admin, Role::Pauser),
+            (Role::Controller, Role::Minter),
+        ]);
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, admin());
+
+        // Assert.
+        assert_eq!(
+            usdc.role_conflicts(),
+            vec![
+                (Role::Admin, Role::Pauser),
+                (Role::Controller, Role::Minter)
+            ]
+        );
+        assert!(<Contract as Rbac>::has_role(&admin(), &Role::Owner));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: invalid role conflict")]
+    fn test_update_role_conflicts_same_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_role_conflicts(vec![(Role::Owner, Role::Owner)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Admin")]
+    fn test_update_role_conflicts_not_admin() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.update_role_conflicts(vec![]);
+    }
+
     #[test]
     fn test_allowance() {
         let usdc: Contract = init_contract();
@@ -1035,7 +2967,7 @@ mod tests {
         // Arrange.
         let mut usdc: Contract = init_contract();
         set_caller(master_minter());
-        usdc.configure_controller(controller(), minter());
+        usdc.configure_controller(controller(), minter(), None);
         set_caller(controller());
 
         // Act.
@@ -1049,7 +2981,7 @@ mod tests {
         let mut usdc: Contract = init_contract();
 
         // Act.
-        usdc.configure_controller(controller(), minter());
+        usdc.configure_controller(controller(), minter(), None);
     }
 
     #[test]
@@ -1061,7 +2993,7 @@ mod tests {
         set_caller(master_minter());
 
         // Act.
-        usdc.configure_controller(controller.clone(), minter.clone());
+        usdc.configure_controller(controller.clone(), minter.clone(), None);
 
         // Assert.
         // Configuring a controller just assigns a minter to the controller. It does not initialize
@@ -1088,8 +3020,8 @@ mod tests {
         set_caller(master_minter());
 
         // Act.
-        usdc.configure_controller(controller.clone(), minter.clone());
-        usdc.configure_controller(controller.clone(), minter2.clone());
+        usdc.configure_controller(controller.clone(), minter.clone(), None);
+        usdc.configure_controller(controller.clone(), minter2.clone(), None);
 
         // Assert.
         assert_eq!(
@@ -1116,7 +3048,7 @@ mod tests {
         let mut usdc: Contract = init_contract();
         set_caller(master_minter());
         let controller2: AccountId = "controller2".parse().unwrap();
-        usdc.configure_controller(controller2.clone(), minter());
+        usdc.configure_controller(controller2.clone(), minter(), None);
 
         // Act.
         usdc.remove_controller(controller2.clone());
@@ -1149,9 +3081,9 @@ mod tests {
         let mut usdc: Contract = init_contract();
         set_caller(master_minter());
         let controller2: AccountId = "controller2".parse().unwrap();
-        usdc.configure_controller(controller2, minter());
+        usdc.configure_controller(controller2, minter(), None);
         let controller3: AccountId = "controller3".parse().unwrap();
-        usdc.configure_controller(controller3, minter());
+        usdc.configure_controller(controller3, minter(), None);
 
         // Act.
         usdc.remove_controller(minter());
@@ -1248,42 +3180,310 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "FiatToken: minter allowance decrement must be greater than 0")]
-    fn test_decrease_minter_allowance_bad_decrement() {
+    fn test_configure_minter_rate_limit() {
         // Arrange.
         let mut usdc: Contract = init_contract();
         set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
 
         // Act.
-        usdc.configure_minter_allowance(U128::from(u128::MAX));
-        usdc.decrease_minter_allowance(U128::from(0));
-    }
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(10));
 
-    #[test]
-    #[should_panic(expected = "FiatToken: attempted to underflow minter allowance")]
-    fn test_decrease_minter_allowance_underflow() {
-        // Arrange.
-        let mut usdc: Contract = init_contract();
-        set_caller(controller());
+        // Assert.
+        assert_eq!(
+            usdc.minter_rate_limit(&minter()),
+            Some(MinterRateLimitView {
+                max_allowance: U128::from(1000),
+                replenish_per_second: U128::from(10),
+            })
+        );
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_rate_limit_configured\",\"data\":{{\"minter_id\":\"{}\",\"max_allowance\":\"1000\",\"replenish_per_second\":\"10\"}}}}", minter())
+        );
 
         // Act.
-        usdc.configure_minter_allowance(U128::from(1));
-        usdc.decrease_minter_allowance(U128::from(2));
+        set_caller_at(minter(), 50_000_000_000);
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(500));
+
+        // Act.
+        set_caller_at(minter(), 200_000_000_000);
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(1000));
     }
 
     #[test]
-    fn test_decrease_minter_allowance() {
+    fn test_mint_with_minter_rate_limit() {
         // Arrange.
         let mut usdc: Contract = init_contract();
+        let to: AccountId = "to_id".parse().unwrap();
+        init_account(&mut usdc, to.clone(), None);
         set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(10));
 
         // Act.
-        usdc.configure_minter_allowance(U128::from(123));
-        usdc.decrease_minter_allowance(U128::from(10));
+        set_caller_at(minter(), 50_000_000_000);
+        usdc.mint(to.clone(), U128::from(300));
 
         // Assert.
-        assert_eq!(usdc.minter_allowance(&minter()), U128::from(113));
-        assert_eq!(
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(200));
+
+        // Act.
+        set_caller_at(minter(), 60_000_000_000);
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(300));
+        assert_eq!(usdc.minters(None, None), vec![(minter(), U128::from(300))]);
+    }
+
+    #[test]
+    fn test_mint_with_minter_rate_limit_keeps_partial_replenishment() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let to: AccountId = "to_id".parse().unwrap();
+        init_account(&mut usdc, to.clone(), None);
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(1));
+
+        // Act.
+        set_caller_at(minter(), 1_500_000_000);
+        usdc.mint(to.clone(), U128::from(1));
+        set_caller_at(minter(), 2_700_000_000);
+        usdc.mint(to, U128::from(1));
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(0));
+        set_caller_at(minter(), 3_000_000_000);
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: mint amount exceeds minter allowance")]
+    fn test_mint_exceeds_replenished_minter_allowance() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let to: AccountId = "to_id".parse().unwrap();
+        init_account(&mut usdc, to.clone(), None);
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(10));
+
+        // Act.
+        set_caller_at(minter(), 50_000_000_000);
+        usdc.mint(to, U128::from(501));
+    }
+
+    #[test]
+    fn test_remove_minter_rate_limit() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(10));
+
+        // Act.
+        set_caller_at(controller(), 30_000_000_000);
+        usdc.configure_minter_rate_limit(U128::from(0), U128::from(0));
+
+        // Assert.
+        assert_eq!(usdc.minter_rate_limit(&minter()), None);
+        set_caller_at(minter(), 100_000_000_000);
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(300));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter is not a minter")]
+    fn test_configure_minter_rate_limit_not_minter() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+        usdc.remove_minter();
+
+        // Act.
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(10));
+    }
+
+    #[test]
+    fn test_update_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.update_max_total_supply(Some(U128::from(1000)), SupplyCapPolicy::Warn);
+
+        // Assert.
+        assert_eq!(usdc.max_total_supply(), Some(U128::from(1000)));
+        assert_eq!(usdc.supply_cap_policy(), SupplyCapPolicy::Warn);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"max_total_supply_updated\",\"data\":{\"max_total_supply\":\"1000\",\"policy\":\"Warn\"}}"
+        );
+    }
+
+    #[test]
+    fn test_supply_headroom() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        init_account(&mut usdc, accounts(1), Some(U128::from(40)));
+
+        // Act & Assert.
+        assert_eq!(usdc.supply_headroom(), None);
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Ignore);
+        assert_eq!(usdc.supply_headroom(), Some(U128::from(60)));
+        usdc.update_max_total_supply(Some(U128::from(30)), SupplyCapPolicy::Ignore);
+        assert_eq!(usdc.supply_headroom(), Some(U128::from(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: mint amount exceeds max total supply")]
+    fn test_mint_exceeds_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        init_account(&mut usdc, accounts(1), Some(U128::from(40)));
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Ignore);
+
+        // Act.
+        set_caller(minter());
+        usdc.mint(accounts(1), U128::from(61));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter allowances exceed max total supply")]
+    fn test_increase_minter_allowance_exceeds_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        init_account(&mut usdc, accounts(1), Some(U128::from(40)));
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(50));
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Fail);
+
+        // Act.
+        set_caller(controller());
+        usdc.increase_minter_allowance(U128::from(11));
+    }
+
+    #[test]
+    fn test_configure_minter_allowance_warns_above_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        init_account(&mut usdc, accounts(1), Some(U128::from(40)));
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Warn);
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_allowance(U128::from(61));
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(61));
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_allowances_exceed_max_total_supply\",\"data\":{\"minter_allowances\":\"61\",\"total_supply\":\"40\",\"max_total_supply\":\"100\"}}"
+        );
+    }
+
+    #[test]
+    fn test_decrease_minter_allowance_above_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Fail);
+        set_caller(controller());
+
+        // Act.
+        usdc.decrease_minter_allowance(U128::from(1));
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(u128::MAX - 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter allowances exceed max total supply")]
+    fn test_configure_minter_rate_limit_exceeds_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Fail);
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_rate_limit(U128::from(101), U128::from(1));
+    }
+
+    #[test]
+    fn test_lower_minter_rate_limit_above_max_total_supply() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+        usdc.configure_minter_allowance(U128::from(0));
+        usdc.configure_minter_rate_limit(U128::from(1000), U128::from(1));
+        set_caller(owner());
+        usdc.update_max_total_supply(Some(U128::from(100)), SupplyCapPolicy::Fail);
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_rate_limit(U128::from(500), U128::from(1));
+
+        // Assert.
+        assert_eq!(
+            usdc.minter_rate_limit(&minter()),
+            Some(MinterRateLimitView {
+                max_allowance: U128::from(500),
+                replenish_per_second: U128::from(1),
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter allowance decrement must be greater than 0")]
+    fn test_decrease_minter_allowance_bad_decrement() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_allowance(U128::from(u128::MAX));
+        usdc.decrease_minter_allowance(U128::from(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: attempted to underflow minter allowance")]
+    fn test_decrease_minter_allowance_underflow() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_allowance(U128::from(1));
+        usdc.decrease_minter_allowance(U128::from(2));
+    }
+
+    #[test]
+    fn test_decrease_minter_allowance() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(controller());
+
+        // Act.
+        usdc.configure_minter_allowance(U128::from(123));
+        usdc.decrease_minter_allowance(U128::from(10));
+
+        // Assert.
+        assert_eq!(usdc.minter_allowance(&minter()), U128::from(113));
+        assert_eq!(
             test_utils::get_logs()[1],
             format!(
                 "EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_configured\",\"data\":{{\"minter_id\":\"{}\",\"minter_allowance\":{}}}}}",
@@ -1300,6 +3500,43 @@ mod tests {
         assert_eq!(usdc.minter_allowance(&accounts(1)), U128::from(0));
     }
 
+    #[test]
+    fn test_controllers_and_minters() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let controller2: AccountId = "controller2".parse().unwrap();
+        let minter2: AccountId = "minter2".parse().unwrap();
+        set_caller(master_minter());
+        usdc.configure_controller(controller2.clone(), minter2.clone(), None);
+        set_caller(controller2.clone());
+        usdc.configure_minter_allowance(U128::from(100));
+
+        // Act & Assert.
+        assert_eq!(
+            usdc.controllers(None, None),
+            vec![
+                (controller(), minter()),
+                (controller2.clone(), minter2.clone())
+            ]
+        );
+        assert_eq!(
+            usdc.controllers(Some(1), Some(1)),
+            vec![(controller2, minter2.clone())]
+        );
+        assert_eq!(
+            usdc.minters(None, None),
+            vec![
+                (minter(), U128::from(u128::MAX)),
+                (minter2.clone(), U128::from(100))
+            ]
+        );
+        assert_eq!(
+            usdc.minters(Some(1), Some(5)),
+            vec![(minter2, U128::from(100))]
+        );
+        assert!(usdc.minters(Some(2), None).is_empty());
+    }
+
     #[test]
     fn test_update_master_minter() {
         // Arrange.
@@ -1307,13 +3544,25 @@ mod tests {
         set_caller(owner());
 
         // Act.
-        usdc.configure_multisig_role(Role::MasterMinter, accounts(2).into());
+        usdc.configure_multisig_role(Role::MasterMinter, accounts(2).into(), None);
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_offered\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\",\"expires_at\":\"{}\",\"role_expires_at\":null}}}}", Role::MasterMinter, accounts(2), ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS)
+        );
+        assert!(!usdc.master_minters().contains(&accounts(2)));
+
+        // Act.
+        set_caller(accounts(2));
+        usdc.accept_role(Role::MasterMinter);
 
         // Assert.
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_configured\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::MasterMinter, accounts(2).to_string())
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_offer_accepted\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::MasterMinter, accounts(2).to_string())
         );
+        assert!(usdc.master_minters().contains(&accounts(2)));
     }
 
     #[test]
@@ -1325,7 +3574,7 @@ mod tests {
         let mut usdc: Contract = init_contract();
 
         // Act.
-        usdc.configure_multisig_role(Role::MasterMinter, accounts(2));
+        usdc.configure_multisig_role(Role::MasterMinter, accounts(2), None);
     }
 
     #[test]
@@ -1793,7 +4042,7 @@ mod tests {
         init_account(&mut usdc, burner.clone(), Some(initial_burner_balance));
         let balance_prior_to_burn: Balance = usdc.ft_total_supply().0;
         set_caller(master_minter());
-        usdc.configure_controller(burner_controller.clone(), burner.clone());
+        usdc.configure_controller(burner_controller.clone(), burner.clone(), None);
 
         // Configure the burner to be a minter.
         set_caller(burner_controller);
@@ -1834,7 +4083,7 @@ mod tests {
         // generic account, we have to first send those tokens to a burner before proceeding.
         init_account(&mut usdc, burner.clone(), Some(initial_burner_balance));
         set_caller(master_minter());
-        usdc.configure_controller(burner_controller.clone(), burner.clone());
+        usdc.configure_controller(burner_controller.clone(), burner.clone(), None);
 
         // Configure the burner to be a minter.
         set_caller(burner_controller);
@@ -1857,7 +4106,7 @@ mod tests {
         // generic account, we have to first send those tokens to a burner before proceeding.
         init_account(&mut usdc, burner.clone(), Some(initial_burner_balance));
         set_caller(master_minter());
-        usdc.configure_controller(burner_controller.clone(), burner.clone());
+        usdc.configure_controller(burner_controller.clone(), burner.clone(), None);
 
         // Configure the burner to be a minter.
         set_caller(burner_controller);
@@ -1880,7 +4129,7 @@ mod tests {
         // generic account, we have to first send those tokens to a burner before proceeding.
         init_account(&mut usdc, burner.clone(), Some(initial_burner_balance));
         set_caller(master_minter());
-        usdc.configure_controller(burner_controller.clone(), burner.clone());
+        usdc.configure_controller(burner_controller.clone(), burner.clone(), None);
 
         // Configure the burner to be a minter.
         set_caller(burner_controller);
@@ -1907,6 +4156,12 @@ mod tests {
         // Arrange.
         let mut usdc: Contract = init_contract();
         set_caller(admin());
+        configure_and_accept_role(&mut usdc, Role::Admin, "admin2".parse().unwrap());
+        configure_and_accept_role(&mut usdc, Role::Admin, "admin3".parse().unwrap());
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, "owner2".parse().unwrap());
+        configure_and_accept_role(&mut usdc, Role::Owner, "owner3".parse().unwrap());
+        set_caller(admin());
 
         // Act.
         usdc.revoke_multisig_role(Role::Admin, admin());
@@ -1926,6 +4181,32 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "FiatToken: cannot revoke a Admin below the minimum of 2 accounts")]
+    fn test_revoke_multisig_role_below_threshold() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let admin2: AccountId = "admin2".parse().unwrap();
+        set_caller(admin());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin2.clone());
+
+        // Act.
+        usdc.revoke_multisig_role(Role::Admin, admin2);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: cannot revoke a Owner below the minimum of 1 accounts")]
+    fn test_revoke_multisig_role_last_member() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.update_multisig_configuration(Some(Role::Owner), 1, U64::from(432000000000000));
+
+        // Act.
+        set_caller(owner());
+        usdc.revoke_multisig_role(Role::Owner, owner());
+    }
+
     #[test]
     fn test_pause() {
         // Arrange.
@@ -1938,8 +4219,9 @@ mod tests {
         // Assert.
         assert_eq!(
             test_utils::get_logs()[0],
-            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"paused\",\"data\":null}"
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"paused\",\"data\":{\"operations\":[\"Transfer\",\"TransferCall\",\"Mint\",\"Burn\",\"Approve\",\"TransferFrom\",\"Storage\"]}}"
         );
+        assert!(usdc.is_paused());
     }
 
     #[test]
@@ -1965,6 +4247,44 @@ mod tests {
         usdc.pause();
     }
 
+    #[test]
+    fn test_views_while_paused() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let holder: AccountId = "holder".parse().unwrap();
+        init_account(&mut usdc, holder.clone(), Some(U128::from(100)));
+        set_caller(pauser());
+        usdc.pause();
+
+        // Act & Assert.
+        assert_eq!(usdc.ft_balance_of(holder.clone()), U128::from(100));
+        assert_eq!(usdc.ft_total_supply(), U128::from(100));
+        assert!(usdc.storage_balance_of(holder.clone()).is_some());
+        assert_eq!(usdc.allowance(&holder, &minter()), U128::from(0));
+        assert_eq!(
+            usdc.minter_allowance(&minter()),
+            U128::from(u128::MAX - 100)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: paused")]
+    fn test_ft_transfer_while_paused() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let holder: AccountId = "holder".parse().unwrap();
+        init_account(&mut usdc, holder.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, accounts(1), None);
+        set_caller(pauser());
+        usdc.pause();
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(holder);
+        context.attached_deposit(ONE_YOCTO);
+        testing_env!(context.build());
+        usdc.ft_transfer(accounts(1), U128::from(10), None);
+    }
+
     #[test]
     fn test_unpause() {
         // Arrange.
@@ -1978,7 +4298,7 @@ mod tests {
         // Assert.
         assert_eq!(
             test_utils::get_logs()[1],
-            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"unpaused\",\"data\":null}"
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"unpaused\",\"data\":{\"operations\":[\"Transfer\",\"TransferCall\",\"Mint\",\"Burn\",\"Approve\",\"TransferFrom\",\"Storage\"]}}"
         );
     }
 
@@ -2006,32 +4326,113 @@ mod tests {
     }
 
     #[test]
-    fn test_update_pauser() {
+    fn test_set_pause_flags() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let pauser_id = accounts(2).clone();
-        set_caller(owner());
+        let holder: AccountId = "holder".parse().unwrap();
+        init_account(&mut usdc, holder.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, accounts(1), None);
+        set_caller(pauser());
 
         // Act.
-        usdc.configure_multisig_role(Role::Pauser, pauser_id.clone());
+        usdc.set_pause_flags(vec![
+            PausableOperation::Mint,
+            PausableOperation::TransferFrom,
+        ]);
+        usdc.set_pause_flags(vec![PausableOperation::Mint, PausableOperation::Burn]);
 
         // Assert.
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_configured\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Pauser, accounts(2).to_string())
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"paused\",\"data\":{\"operations\":[\"Mint\",\"TransferFrom\"]}}"
         );
-
-        // Check if pauser has changed.
-        // Arrange.
-        set_caller(pauser_id.clone());
-
-        // Act.
-        usdc.pause();
-
-        // Assert.
         assert_eq!(
-            test_utils::get_logs()[0],
-            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"paused\",\"data\":null}"
+            test_utils::get_logs()[1],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"paused\",\"data\":{\"operations\":[\"Burn\"]}}"
+        );
+        assert_eq!(
+            test_utils::get_logs()[2],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"unpaused\",\"data\":{\"operations\":[\"TransferFrom\"]}}"
+        );
+        assert_eq!(
+            usdc.paused_operations(),
+            vec![PausableOperation::Mint, PausableOperation::Burn]
+        );
+
+        // Transfers are not paused.
+        let mut context: VMContextBuilder = set_caller(holder.clone());
+        context.attached_deposit(ONE_YOCTO);
+        testing_env!(context.build());
+        usdc.ft_transfer(accounts(1), U128::from(10), None);
+        assert_eq!(usdc.ft_balance_of(accounts(1)), U128::from(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: paused")]
+    fn test_set_pause_flags_mint_paused() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let holder: AccountId = "holder".parse().unwrap();
+        init_account(&mut usdc, holder.clone(), None);
+        set_caller(pauser());
+        usdc.set_pause_flags(vec![PausableOperation::Mint]);
+
+        // Act.
+        set_caller(minter());
+        usdc.mint(holder, U128::from(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: pause flags are unchanged")]
+    fn test_set_pause_flags_unchanged() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        usdc.set_pause_flags(vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Pauser")]
+    fn test_set_pause_flags_not_pauser() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.set_pause_flags(vec![PausableOperation::Mint]);
+    }
+
+    #[test]
+    fn test_update_pauser() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let pauser_id = accounts(2).clone();
+        set_caller(owner());
+
+        // Act.
+        usdc.configure_multisig_role(Role::Pauser, pauser_id.clone(), None);
+        set_caller(pauser_id.clone());
+        usdc.accept_role(Role::Pauser);
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_configured\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Pauser, accounts(2).to_string())
+        );
+
+        // Check if pauser has changed.
+        // Arrange.
+        set_caller(pauser_id.clone());
+
+        // Act.
+        usdc.pause();
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"paused\",\"data\":{\"operations\":[\"Transfer\",\"TransferCall\",\"Mint\",\"Burn\",\"Approve\",\"TransferFrom\",\"Storage\"]}}"
         );
     }
 
@@ -2044,7 +4445,7 @@ mod tests {
         let mut usdc: Contract = init_contract();
 
         // Act.
-        usdc.configure_multisig_role(Role::Pauser, accounts(2).into());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2).into(), None);
     }
 
     #[test]
@@ -2059,317 +4460,1789 @@ mod tests {
         // Act & Assert
         assert_eq!(usdc.blocklister(), blocklister_account);
         assert_eq!(usdc.is_blocklisted(to_block_account.clone()), false);
-        usdc.blocklist(to_block_account.clone());
+        usdc.blocklist(to_block_account.clone(), None, None);
         assert_eq!(usdc.is_blocklisted(to_block_account.clone()), true);
         usdc.unblocklist(to_block_account.clone());
         assert_eq!(usdc.is_blocklisted(to_block_account.clone()), false);
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":\"{}\"}}}}", to_block_account.to_string())
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\",\"reason\":null,\"reference\":null,\"blocklisted_at\":\"0\"}}}}", to_block_account.to_string(), blocklister())
         );
         assert_eq!(
             test_utils::get_logs()[1],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"unblocklist\",\"data\":{{\"account_id\":\"{}\"}}}}", to_block_account.to_string())
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"unblocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\"}}}}", to_block_account.to_string(), blocklister())
         );
     }
 
     #[test]
-    #[should_panic(expected = "FiatToken: caller is not a Blocklister")]
-    fn test_blocklist_not_blocklister() {
+    fn test_blocklisted_accounts() {
         // Arrange.
-        let not_blocklister_account: AccountId = "not_blocklister".parse().unwrap();
-        set_caller(not_blocklister_account);
-
         let mut usdc: Contract = init_contract();
-
-        let to_block_account: AccountId = "block_me".parse().unwrap();
-        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
-
-        // Act & Assert
-        assert_eq!(usdc.is_blocklisted(to_block_account.clone()), false);
-        usdc.blocklist(to_block_account.clone());
+        let blocked1: AccountId = "block_me1".parse().unwrap();
+        let blocked2: AccountId = "block_me2".parse().unwrap();
+        let blocked3: AccountId = "block_me3".parse().unwrap();
+        _blocklist(&mut usdc, blocked1.clone());
+        _blocklist(&mut usdc, blocked2.clone());
+        _blocklist(&mut usdc, blocked3.clone());
+        usdc.unblocklist(blocked2);
+
+        // Act & Assert.
+        let blocklisted: Vec<AccountId> = usdc.blocklisted_accounts(None, None);
+        assert_eq!(blocklisted.len(), 2);
+        assert!(blocklisted.contains(&blocked1));
+        assert!(blocklisted.contains(&blocked3));
+        assert_eq!(usdc.blocklisted_accounts(Some(1), Some(1)).len(), 1);
+        assert!(usdc.blocklisted_accounts(Some(2), None).is_empty());
     }
 
     #[test]
-    fn test_update_blocklister() {
+    fn test_blocklist_entry() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_blocklister_account: AccountId = "new_blocklister".parse().unwrap();
-        init_account(
-            &mut usdc,
-            new_blocklister_account.clone(),
-            Some(U128::from(100)),
+        let mut context: VMContextBuilder = set_caller(blocklister());
+        context.block_timestamp(1000);
+        testing_env!(context.build());
+
+        // Act.
+        usdc.blocklist(
+            accounts(2),
+            Some(BlocklistReason::CourtOrder),
+            Some("CASE-42".to_string()),
         );
-        set_caller(owner());
 
-        // Act & Assert
-        assert_eq!(usdc.blocklister(), blocklister());
-        usdc.update_blocklister(new_blocklister_account.clone());
-        assert_eq!(usdc.blocklister(), new_blocklister_account);
+        // Assert.
+        assert_eq!(
+            usdc.blocklist_entry(accounts(2)),
+            Some(BlocklistEntry {
+                reason: Some(BlocklistReason::CourtOrder),
+                reference: Some("CASE-42".to_string()),
+                blocklister_id: blocklister(),
+                blocklisted_at: U64::from(1000),
+            })
+        );
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklister_changed\",\"data\":{{\"new_blocklister_id\":\"{}\"}}}}", new_blocklister_account.to_string())
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\",\"reason\":\"CourtOrder\",\"reference\":\"CASE-42\",\"blocklisted_at\":\"1000\"}}}}", accounts(2), blocklister())
         );
+
+        // Act.
+        usdc.unblocklist(accounts(2));
+
+        // Assert.
+        assert_eq!(usdc.blocklist_entry(accounts(2)), None);
     }
 
     #[test]
-    #[should_panic(expected = "FiatToken: caller is not a Owner")]
-    fn test_update_blocklister_not_owner() {
+    #[should_panic(expected = "FiatToken: charlie is blocklisted")]
+    fn test_blocklist_already_blocklisted() {
         // Arrange.
-        let not_owner_account: AccountId = "not_owner".parse().unwrap();
-        set_caller(not_owner_account.clone());
-
         let mut usdc: Contract = init_contract();
+        set_caller(blocklister());
+        usdc.blocklist(
+            accounts(2),
+            Some(BlocklistReason::CourtOrder),
+            Some("CASE-42".to_string()),
+        );
 
-        // Act & Assert
-        assert_eq!(usdc.blocklister(), blocklister());
-        usdc.update_blocklister(not_owner_account);
+        // Act.
+        usdc.blocklist(accounts(2), Some(BlocklistReason::Fraud), None);
     }
 
     #[test]
-    fn test_configure_owner() {
+    #[should_panic(expected = "FiatToken: reference cannot be longer than 256 bytes")]
+    fn test_blocklist_reference_too_long() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_owner_id: AccountId = "new_owner".parse().unwrap();
-        set_caller(owner());
+        set_caller(blocklister());
 
         // Act.
-        usdc.configure_multisig_role(Role::Owner, new_owner_id.clone());
-
-        // Assert.
-        assert!(usdc.owners().contains(&&new_owner_id));
-        assert_eq!(
-            test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_configured\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Owner, new_owner_id.to_string())
+        usdc.blocklist(
+            accounts(2),
+            Some(BlocklistReason::Sanctions),
+            Some("a".repeat(MAX_BLOCKLIST_REFERENCE_LENGTH + 1)),
         );
     }
 
     #[test]
-    fn test_configure_admin() {
+    fn test_wipe_blocklisted_funds_burn() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_admin_id: AccountId = "new_admin".parse().unwrap();
-        set_caller(admin());
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
+        _blocklist(&mut usdc, to_block_account.clone());
+        set_caller(owner());
 
         // Act.
-        let mut new_admin_ids: Vec<AccountId> = Vec::new();
-        new_admin_ids.insert(0, new_admin_id.clone());
-        usdc.configure_multisig_role(Role::Admin, new_admin_id.clone());
+        usdc.wipe_blocklisted_funds(to_block_account.clone(), None);
 
         // Assert.
-        assert!(usdc.admins().contains(&&new_admin_id));
+        assert_eq!(usdc.ft_balance_of(to_block_account.clone()), U128::from(0));
+        assert_eq!(usdc.ft_total_supply(), U128::from(0));
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_configured\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Admin, new_admin_id.to_string())
+            format!("EVENT_JSON:{{\"standard\":\"nep141\",\"version\":\"1.0.0\",\"event\":\"ft_burn\",\"data\":[{{\"owner_id\":\"{}\",\"amount\":\"100\",\"memo\":\"Wiped funds of blocklisted account {}\"}}]}}", to_block_account, to_block_account)
+        );
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklisted_funds_wiped\",\"data\":{{\"account_id\":\"{}\",\"destination\":null,\"amount\":\"100\"}}}}", to_block_account)
         );
     }
 
     #[test]
-    #[should_panic(expected = "RemovalNotAllowed(RequestStillValid)")]
-    fn test_remove_multisig_request() {
+    fn test_wipe_blocklisted_funds_to_custody() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_master_minter_id: AccountId = "new_master_minter".parse().unwrap();
-        let request_id: u32 = usdc.get_next_multisig_request_id();
-        let update_master_minter_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
-            role: Role::MasterMinter,
-            account_id: new_master_minter_id.clone(),
-        };
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        let custody_account: AccountId = "custody".parse().unwrap();
+        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, custody_account.clone(), None);
+        _blocklist(&mut usdc, to_block_account.clone());
         set_caller(owner());
-        usdc.create_multisig_request(update_master_minter_action);
 
         // Act.
-        usdc.remove_multisig_request(request_id);
+        usdc.wipe_blocklisted_funds(to_block_account.clone(), Some(custody_account.clone()));
+
+        // Assert.
+        assert_eq!(usdc.ft_balance_of(to_block_account), U128::from(0));
+        assert_eq!(usdc.ft_balance_of(custody_account), U128::from(100));
+        assert_eq!(usdc.ft_total_supply(), U128::from(100));
     }
 
     #[test]
-    #[should_panic(expected = "FiatToken: caller is not a Owner")]
-    fn test_create_multisig_request_missing_role() {
+    #[should_panic(expected = "FiatToken: custody is not allowlisted")]
+    fn test_wipe_blocklisted_funds_to_not_allowlisted_custody() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_master_minter_id: AccountId = "new_master_minter".parse().unwrap();
-        let update_master_minter_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
-            role: Role::MasterMinter,
-            account_id: new_master_minter_id.clone(),
-        };
-        usdc._grant_multisig_role(new_master_minter_id, &Role::Controller);
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        let custody_account: AccountId = "custody".parse().unwrap();
+        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, custody_account.clone(), None);
+        _blocklist(&mut usdc, to_block_account.clone());
+        enable_allowlist(&mut usdc, vec![to_block_account.clone()]);
 
         // Act.
-        usdc.create_multisig_request(update_master_minter_action);
+        usdc.wipe_blocklisted_funds(to_block_account, Some(custody_account));
     }
 
     #[test]
-    #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: ExecutionEligibility(InsufficientApprovals { current: 1, required: 2 })"
-    )]
-    fn test_multisig_update_blocklister_insufficient_approvals() {
+    #[should_panic(expected = "FiatToken: custody is blocklisted by the compliance oracle")]
+    fn test_wipe_blocklisted_funds_to_custody_blocklisted_by_oracle() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_blocklister_id: AccountId = "new_blocklister".parse().unwrap();
-        let update_blocklister_action: FiatTokenAction = FiatTokenAction::UpdateBlocklister {
-            new_blocklister_id: new_blocklister_id.clone(),
-        };
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        let custody_account: AccountId = "custody".parse().unwrap();
+        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, custody_account.clone(), None);
+        _blocklist(&mut usdc, to_block_account.clone());
+        sync_oracle_blocklist(&mut usdc, vec![custody_account.clone()]);
         set_caller(owner());
-        let owner2: AccountId = "owner2".parse().unwrap();
-        usdc.configure_multisig_role(Role::Owner, owner2);
 
         // Act.
-        let update_blocklister_request_id: u32 =
-            usdc.create_multisig_request(update_blocklister_action);
-        usdc.approve_multisig_request(update_blocklister_request_id.clone());
-        assert_eq!(
-            test_utils::get_logs()[1],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{}}}}}", update_blocklister_request_id.to_string())
-        );
-        usdc.execute_multisig_request(update_blocklister_request_id.clone());
+        usdc.wipe_blocklisted_funds(to_block_account, Some(custody_account));
     }
 
     #[test]
-    #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: ApprovalError(AlreadyApprovedByAccount)"
-    )]
-    fn test_multisig_update_blocklister_same_approver() {
+    #[should_panic(expected = "FiatToken: block_me is not blocklisted")]
+    fn test_wipe_blocklisted_funds_not_blocklisted() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_blocklister_id: AccountId = "new_blocklister".parse().unwrap();
-        let update_blocklister_action: FiatTokenAction = FiatTokenAction::UpdateBlocklister {
-            new_blocklister_id: new_blocklister_id.clone(),
-        };
+        let account_id: AccountId = "block_me".parse().unwrap();
+        init_account(&mut usdc, account_id.clone(), Some(U128::from(100)));
         set_caller(owner());
 
         // Act.
-        let update_blocklister_request_id: u32 =
-            usdc.create_multisig_request(update_blocklister_action);
-        usdc.approve_multisig_request(update_blocklister_request_id);
-        usdc.approve_multisig_request(update_blocklister_request_id.clone());
+        usdc.wipe_blocklisted_funds(account_id, None);
     }
 
     #[test]
-    #[should_panic(expected = "FiatToken: caller is not a Owner")]
-    fn test_multisig_configure_pauser_grant_then_revoke_role() {
+    fn test_blocklist_many_and_unblocklist_many() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let new_pauser_id: AccountId = "new_pauser".parse().unwrap();
-        let pauser2: AccountId = "pauser2".parse().unwrap();
-        set_caller(pauser());
-        let configure_pauser_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
-            role: Role::Pauser,
-            account_id: new_pauser_id.clone(),
-        };
+        set_caller(blocklister());
 
         // Act.
-        set_caller(pauser());
-        let configure_pauser_request_id: u32 =
-            usdc.create_multisig_request(configure_pauser_action);
-        usdc.approve_multisig_request(configure_pauser_request_id);
+        usdc.blocklist_many(vec![accounts(2), accounts(3)], None, None);
+
+        // Assert.
+        assert!(usdc.is_blocklisted(accounts(2)));
+        assert!(usdc.is_blocklisted(accounts(3)));
+        assert_eq!(test_utils::get_logs().len(), 2);
         assert_eq!(
-            test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{}}}}}", configure_pauser_request_id.to_string())
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\",\"reason\":null,\"reference\":null,\"blocklisted_at\":\"0\"}}}}", accounts(3), blocklister())
+        );
+
+        // Act.
+        usdc.unblocklist_many(vec![accounts(3), accounts(2)]);
+
+        // Assert.
+        assert!(!usdc.is_blocklisted(accounts(2)));
+        assert!(!usdc.is_blocklisted(accounts(3)));
+        assert_eq!(
+            test_utils::get_logs()[2],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"unblocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\"}}}}", accounts(3), blocklister())
         );
-        // Grant the account the Multisig and Owner Role but then revoke it.
-        usdc._revoke_multisig_role(&pauser2, &Role::Pauser);
-        set_caller(pauser2);
-        usdc.approve_multisig_request(configure_pauser_request_id.clone());
     }
 
     #[test]
-    fn test_multisig_configure_minter_allowance() {
+    #[should_panic(expected = "FiatToken: bob is blocklisted")]
+    fn test_blocklist_many_already_blocklisted() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let minter2: AccountId = "minter2".parse().unwrap();
-        let controller2a: AccountId = "controller2a".parse().unwrap();
-        let controller2b: AccountId = "controller2b".parse().unwrap();
-        set_caller(master_minter());
-        usdc.configure_controller(controller2a.clone(), minter2.clone());
-        usdc.configure_controller(controller2b.clone(), minter2.clone());
-        let configure_minter_allowance_action: FiatTokenAction =
-            FiatTokenAction::ConfigureMinterAllowance {
-                controller_id: controller2a.clone(),
-                minter_allowance: U128::from(12345),
-            };
-        set_caller(controller2a);
+        set_caller(blocklister());
+        usdc.blocklist(accounts(1), None, None);
 
         // Act.
-        let configure_minter_allowance_request_id: u32 =
-            usdc.create_multisig_request(configure_minter_allowance_action);
-        usdc.approve_multisig_request(configure_minter_allowance_request_id);
-        assert_eq!(
-            test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{}}}}}", configure_minter_allowance_request_id.to_string())
-        );
-        set_caller(controller2b);
-        usdc.approve_multisig_request(configure_minter_allowance_request_id.clone());
-        usdc.execute_multisig_request(configure_minter_allowance_request_id.clone());
-
-        // Assert.
-        assert!(usdc.is_minter(&minter2));
-        assert_eq!(
-            test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_configured\",\"data\":{{\"minter_id\":\"{}\",\"minter_allowance\":\"12345\"}}}}", minter2.to_string())
-        );
+        usdc.blocklist_many(vec![accounts(2), accounts(1)], None, None);
     }
 
     #[test]
-    #[should_panic(
-        expected = "FiatToken: can only approve requests to configure the allowance of your own minter"
-    )]
-    fn test_multisig_only_approve_requests_to_configure_own_minter() {
+    #[should_panic(expected = "FiatToken: batch contains duplicate accounts")]
+    fn test_blocklist_many_duplicate_accounts() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let minter2: AccountId = "minter2".parse().unwrap();
-        let controller2: AccountId = "controller2".parse().unwrap();
-        set_caller(master_minter());
-        usdc.configure_controller(controller2.clone(), minter2.clone());
-        let configure_minter_allowance_action: FiatTokenAction =
-            FiatTokenAction::ConfigureMinterAllowance {
-                controller_id: controller2.clone(),
-                minter_allowance: U128::from(12345),
-            };
-        set_caller(controller());
+        set_caller(blocklister());
 
         // Act.
-        // Attempt to approve (as controller2) a request for configuring controller's minter.
-        let configure_minter_allowance_request_id: u32 =
-            usdc.create_multisig_request(configure_minter_allowance_action);
-        usdc.approve_multisig_request(configure_minter_allowance_request_id);
+        usdc.blocklist_many(vec![accounts(2), accounts(3), accounts(2)], None, None);
     }
 
     #[test]
-    fn test_multisig_decrease_minter_allowance() {
+    #[should_panic(expected = "FiatToken: batch must contain between 1 and 100 accounts")]
+    fn test_blocklist_many_too_large() {
         // Arrange.
         let mut usdc: Contract = init_contract();
-        let minter2: AccountId = "minter2".parse().unwrap();
-        let controller2a: AccountId = "controller2a".parse().unwrap();
+        set_caller(blocklister());
+        let account_ids: Vec<AccountId> = (0..=MAX_ACCOUNT_BATCH_SIZE)
+            .map(|index| format!("account{index}").parse().unwrap())
+            .collect();
+
+        // Act.
+        usdc.blocklist_many(account_ids, None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: carol is not blocklisted")]
+    fn test_unblocklist_many_not_blocklisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(blocklister());
+        usdc.blocklist(accounts(1), None, None);
+
+        // Act.
+        usdc.unblocklist_many(vec![accounts(1), accounts(2)]);
+    }
+
+    #[test]
+    fn test_allowlist_many_and_unallowlist_many() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.add_allowlist_manager(allowlist_manager());
+        set_caller(allowlist_manager());
+
+        // Act.
+        usdc.allowlist_many(vec![accounts(2), accounts(3)]);
+
+        // Assert.
+        assert!(usdc.is_allowlisted(accounts(2)));
+        assert!(usdc.is_allowlisted(accounts(3)));
+        assert_eq!(
+            usdc.allowlisted_accounts(None, None),
+            vec![accounts(2), accounts(3)]
+        );
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"allowlist\",\"data\":{{\"account_id\":\"{}\",\"allowlist_manager_id\":\"{}\"}}}}", accounts(3), allowlist_manager())
+        );
+
+        // Act.
+        usdc.unallowlist_many(vec![accounts(3), accounts(2)]);
+
+        // Assert.
+        assert!(!usdc.is_allowlisted(accounts(2)));
+        assert!(!usdc.is_allowlisted(accounts(3)));
+        assert_eq!(
+            test_utils::get_logs()[2],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"unallowlist\",\"data\":{{\"account_id\":\"{}\",\"allowlist_manager_id\":\"{}\"}}}}", accounts(3), allowlist_manager())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: bob is allowlisted")]
+    fn test_allowlist_many_already_allowlisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.add_allowlist_manager(allowlist_manager());
+        set_caller(allowlist_manager());
+        usdc.allowlist_many(vec![accounts(1)]);
+
+        // Act.
+        usdc.allowlist_many(vec![accounts(2), accounts(1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a AllowlistManager")]
+    fn test_allowlist_many_not_allowlist_manager() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.allowlist_many(vec![accounts(1)]);
+    }
+
+    #[test]
+    fn test_add_and_remove_allowlist_manager() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.add_allowlist_manager(allowlist_manager());
+
+        // Assert.
+        assert_eq!(
+            usdc.allowlist_managers(None, None),
+            vec![allowlist_manager()]
+        );
+
+        // Act.
+        usdc.remove_allowlist_manager(allowlist_manager());
+
+        // Assert.
+        assert!(usdc.allowlist_managers(None, None).is_empty());
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"allowlist_manager_removed\",\"data\":{{\"allowlist_manager_id\":\"{}\"}}}}", allowlist_manager())
+        );
+    }
+
+    #[test]
+    fn test_set_allowlist_mode() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.set_allowlist_mode(true);
+
+        // Assert.
+        assert!(usdc.is_allowlist_enabled());
+        assert!(usdc.contract_status().allowlist_enabled);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"allowlist_mode_updated\",\"data\":{\"enabled\":true}}"
+        );
+    }
+
+    #[test]
+    fn test_allowlist_mode_allowlisted_parties() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        init_account(&mut usdc, sender.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, receiver.clone(), None);
+        enable_allowlist(&mut usdc, vec![minter(), sender.clone(), receiver.clone()]);
+
+        // Act.
+        let mut context: VMContextBuilder = get_context(sender.clone());
+        context.attached_deposit(1);
+        testing_env!(context.build());
+        usdc.ft_transfer(receiver.clone(), U128::from(50), None);
+        set_caller(minter());
+        usdc.mint(receiver.clone(), U128::from(25));
+
+        // Assert.
+        assert_eq!(usdc.ft_balance_of(sender).0, 50);
+        assert_eq!(usdc.ft_balance_of(receiver).0, 75);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: to is not allowlisted")]
+    fn test_allowlist_mode_ft_transfer_receiver_not_allowlisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        init_account(&mut usdc, sender.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, receiver.clone(), None);
+        enable_allowlist(&mut usdc, vec![sender.clone()]);
+
+        // Act.
+        let mut context: VMContextBuilder = get_context(sender);
+        context.attached_deposit(1);
+        testing_env!(context.build());
+        usdc.ft_transfer(receiver, U128::from(50), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: minter is not allowlisted")]
+    fn test_allowlist_mode_mint_minter_not_allowlisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let receiver: AccountId = "to".parse().unwrap();
+        init_account(&mut usdc, receiver.clone(), None);
+        enable_allowlist(&mut usdc, vec![receiver.clone()]);
+
+        // Act.
+        set_caller(minter());
+        usdc.mint(receiver, U128::from(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: bob is not allowlisted")]
+    fn test_allowlist_mode_storage_deposit_not_allowlisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        enable_allowlist(&mut usdc, vec![accounts(0)]);
+
+        // Act.
+        let account_storage_deposit: u128 = usdc.storage_balance_bounds().min.0;
+        let mut context: VMContextBuilder = get_context(accounts(0));
+        context.attached_deposit(account_storage_deposit);
+        testing_env!(context.build());
+        usdc.storage_deposit(Some(accounts(1)), None);
+    }
+
+    #[test]
+    fn test_update_compliance_oracle() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.update_compliance_oracle(Some(compliance_oracle()));
+
+        // Assert.
+        assert_eq!(usdc.compliance_oracle(), Some(compliance_oracle()));
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"compliance_oracle_updated\",\"data\":{{\"compliance_oracle\":\"{}\"}}}}", compliance_oracle())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: no compliance oracle is configured")]
+    fn test_sync_blocklist_from_oracle_no_oracle() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+
+        // Act.
+        usdc.sync_blocklist_from_oracle(vec![accounts(1)]);
+    }
+
+    #[test]
+    fn test_on_oracle_blocklist_synced() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        sync_oracle_blocklist(&mut usdc, vec![accounts(1), accounts(2)]);
+
+        // Act.
+        usdc.on_oracle_blocklist_synced(
+            compliance_oracle(),
+            vec![accounts(1), accounts(2), accounts(3)],
+            Ok(vec![true, false, false]),
+        );
+
+        // Assert.
+        assert!(usdc.is_blocklisted_by_oracle(accounts(1)));
+        assert!(!usdc.is_blocklisted_by_oracle(accounts(2)));
+        assert!(!usdc.is_blocklisted_by_oracle(accounts(3)));
+        // Only accounts whose state changed are reported.
+        assert_eq!(test_utils::get_logs().len(), 3);
+        assert_eq!(
+            test_utils::get_logs()[2],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"oracle_blocklist_synced\",\"data\":{{\"compliance_oracle\":\"{}\",\"account_id\":\"{}\",\"blocklisted\":false}}}}", compliance_oracle(), accounts(2))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: invalid compliance oracle response")]
+    fn test_on_oracle_blocklist_synced_invalid_response() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(accounts(0));
+
+        // Act.
+        usdc.on_oracle_blocklist_synced(
+            compliance_oracle(),
+            vec![accounts(1), accounts(2)],
+            Ok(vec![true]),
+        );
+    }
+
+    #[test]
+    fn test_oracle_blocklist_applies_to_current_oracle_only() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        sync_oracle_blocklist(&mut usdc, vec![accounts(1)]);
+        set_caller(owner());
+
+        // Act.
+        usdc.update_compliance_oracle(None);
+
+        // Assert.
+        assert!(!usdc.is_blocklisted_by_oracle(accounts(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: to is blocklisted by the compliance oracle")]
+    fn test_ft_transfer_blocklisted_by_oracle() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        init_account(&mut usdc, sender.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, receiver.clone(), None);
+        sync_oracle_blocklist(&mut usdc, vec![receiver.clone()]);
+
+        // Act.
+        let mut context: VMContextBuilder = get_context(sender);
+        context.attached_deposit(1);
+        testing_env!(context.build());
+        usdc.ft_transfer(receiver, U128::from(50), None);
+    }
+
+    #[test]
+    fn test_ft_transfer_call_screened_by_oracle() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        let amount: U128 = U128::from(50);
+        init_account(&mut usdc, sender.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, receiver.clone(), None);
+        set_caller(owner());
+        usdc.update_compliance_oracle(Some(compliance_oracle()));
+
+        // Act.
+        let mut context: VMContextBuilder = get_context(sender.clone());
+        context.attached_deposit(1);
+        testing_env!(context.build());
+        let is_promise: bool = matches!(
+            usdc.ft_transfer_call(receiver.clone(), amount, None, "Msg".to_string()),
+            PromiseOrValue::Promise(_)
+        );
+
+        // Assert.
+        assert!(is_promise);
+        assert_eq!(usdc.ft_balance_of(receiver.clone()).0, 0);
+
+        // Act.
+        set_caller(accounts(0));
+        usdc.on_ft_transfer_call_screened(
+            sender.clone(),
+            receiver.clone(),
+            amount,
+            None,
+            "Msg".to_string(),
+            Ok(vec![false, false]),
+        );
+
+        // Assert.
+        assert_eq!(usdc.ft_balance_of(sender).0, 50);
+        assert_eq!(usdc.ft_balance_of(receiver).0, 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: from is blocklisted by the compliance oracle")]
+    fn test_on_ft_transfer_call_screened_blocklisted() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        init_account(&mut usdc, sender.clone(), Some(U128::from(100)));
+        init_account(&mut usdc, receiver.clone(), None);
+        set_caller(accounts(0));
+
+        // Act.
+        usdc.on_ft_transfer_call_screened(
+            sender,
+            receiver,
+            U128::from(50),
+            None,
+            "Msg".to_string(),
+            Ok(vec![true, false]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: compliance oracle call failed")]
+    fn test_on_ft_transfer_call_screened_oracle_failed() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let sender: AccountId = "from".parse().unwrap();
+        let receiver: AccountId = "to".parse().unwrap();
+        set_caller(accounts(0));
+
+        // Act.
+        usdc.on_ft_transfer_call_screened(
+            sender,
+            receiver,
+            U128::from(50),
+            None,
+            "Msg".to_string(),
+            Err(PromiseError::Failed),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Blocklister")]
+    fn test_blocklist_not_blocklister() {
+        // Arrange.
+        let not_blocklister_account: AccountId = "not_blocklister".parse().unwrap();
+        set_caller(not_blocklister_account);
+
+        let mut usdc: Contract = init_contract();
+
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        init_account(&mut usdc, to_block_account.clone(), Some(U128::from(100)));
+
+        // Act & Assert
+        assert_eq!(usdc.is_blocklisted(to_block_account.clone()), false);
+        usdc.blocklist(to_block_account.clone(), None, None);
+    }
+
+    #[test]
+    fn test_update_blocklister() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_blocklister_account: AccountId = "new_blocklister".parse().unwrap();
+        init_account(
+            &mut usdc,
+            new_blocklister_account.clone(),
+            Some(U128::from(100)),
+        );
+        set_caller(owner());
+
+        // Act & Assert
+        assert_eq!(usdc.blocklister(), blocklister());
+        usdc.update_blocklister(new_blocklister_account.clone());
+        assert_eq!(usdc.blocklister(), new_blocklister_account);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklister_changed\",\"data\":{{\"new_blocklister_id\":\"{}\"}}}}", new_blocklister_account.to_string())
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_blocklister() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let blocklister2: AccountId = "blocklister2".parse().unwrap();
+        let to_block_account: AccountId = "block_me".parse().unwrap();
+        set_caller(owner());
+
+        // Act.
+        usdc.add_blocklister(blocklister2.clone());
+        set_caller(blocklister2.clone());
+        usdc.blocklist(to_block_account.clone(), None, None);
+
+        // Assert.
+        assert_eq!(
+            usdc.blocklisters(None, None),
+            vec![blocklister(), blocklister2.clone()]
+        );
+        assert_eq!(usdc.blocklister(), blocklister());
+        assert!(usdc.is_blocklisted(to_block_account.clone()));
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":\"{}\",\"blocklister_id\":\"{}\",\"reason\":null,\"reference\":null,\"blocklisted_at\":\"0\"}}}}", to_block_account, blocklister2)
+        );
+
+        // Act.
+        set_caller(owner());
+        usdc.remove_blocklister(blocklister());
+
+        // Assert.
+        assert_eq!(usdc.blocklisters(None, None), vec![blocklister2.clone()]);
+        assert_eq!(usdc.blocklister(), blocklister2);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklister_removed\",\"data\":{{\"blocklister_id\":\"{}\"}}}}", blocklister())
+        );
+    }
+
+    #[test]
+    fn test_blocklister_returns_lowest_account_id() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        let blocklister0: AccountId = "a_blocklister".parse().unwrap();
+
+        // Act.
+        usdc.add_blocklister(blocklister0.clone());
+
+        // Assert.
+        assert_eq!(
+            usdc.blocklisters(None, None),
+            vec![blocklister(), blocklister0.clone()]
+        );
+        assert_eq!(usdc.blocklister(), blocklister0);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: cannot remove the last blocklister")]
+    fn test_remove_last_blocklister() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.remove_blocklister(blocklister());
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Owner")]
+    fn test_add_blocklister_not_owner() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(blocklister());
+
+        // Act.
+        usdc.add_blocklister(accounts(2));
+    }
+
+    #[test]
+    fn test_update_blocklister_replaces_every_blocklister() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_blocklister_account: AccountId = "new_blocklister".parse().unwrap();
+        set_caller(owner());
+        usdc.add_blocklister(accounts(2));
+
+        // Act.
+        usdc.update_blocklister(new_blocklister_account.clone());
+
+        // Assert.
+        assert_eq!(usdc.blocklisters(None, None), vec![new_blocklister_account]);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Owner")]
+    fn test_update_blocklister_not_owner() {
+        // Arrange.
+        let not_owner_account: AccountId = "not_owner".parse().unwrap();
+        set_caller(not_owner_account.clone());
+
+        let mut usdc: Contract = init_contract();
+
+        // Act & Assert
+        assert_eq!(usdc.blocklister(), blocklister());
+        usdc.update_blocklister(not_owner_account);
+    }
+
+    #[test]
+    fn test_configure_owner() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_owner_id: AccountId = "new_owner".parse().unwrap();
+        set_caller(owner());
+
+        // Act.
+        usdc.configure_multisig_role(Role::Owner, new_owner_id.clone(), None);
+        set_caller(new_owner_id.clone());
+        usdc.accept_role(Role::Owner);
+
+        // Assert.
+        assert!(usdc.owners().contains(&&new_owner_id));
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_offer_accepted\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Owner, new_owner_id.to_string())
+        );
+    }
+
+    #[test]
+    fn test_configure_admin() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_admin_id: AccountId = "new_admin".parse().unwrap();
+        set_caller(admin());
+
+        // Act.
+        let mut new_admin_ids: Vec<AccountId> = Vec::new();
+        new_admin_ids.insert(0, new_admin_id.clone());
+        usdc.configure_multisig_role(Role::Admin, new_admin_id.clone(), None);
+        set_caller(new_admin_id.clone());
+        usdc.accept_role(Role::Admin);
+
+        // Assert.
+        assert!(usdc.admins().contains(&&new_admin_id));
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_offer_accepted\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Admin, new_admin_id.to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: role has not been offered to the caller")]
+    fn test_accept_role_not_offered() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(accounts(2));
+
+        // Act.
+        usdc.accept_role(Role::Admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: role offer has expired")]
+    fn test_accept_role_expired() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.configure_multisig_role(Role::Admin, accounts(2), None);
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(accounts(2));
+        context.block_timestamp(ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS);
+        testing_env!(context.build());
+        usdc.accept_role(Role::Admin);
+    }
+
+    #[test]
+    fn test_role_offers() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), None);
+        usdc.configure_multisig_role(Role::Owner, accounts(3), None);
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(owner());
+        context.block_timestamp(ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS);
+        testing_env!(context.build());
+        let role_offers: Vec<RoleOfferView> = usdc.role_offers(None, None);
+
+        // Assert.
+        assert_eq!(role_offers.len(), 2);
+        assert_eq!(role_offers[0].account_id, accounts(2));
+        assert_eq!(role_offers[0].role, Role::Pauser);
+        assert_eq!(
+            role_offers[0].expires_at,
+            U64::from(ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS)
+        );
+        assert!(role_offers[0].is_expired);
+        assert_eq!(usdc.role_offers(Some(1), Some(1))[0].role, Role::Owner);
+    }
+
+    #[test]
+    fn test_remove_expired_role_offer() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), None);
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(accounts(3));
+        context.block_timestamp(ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS);
+        testing_env!(context.build());
+        usdc.remove_expired_role_offer(Role::Pauser, accounts(2));
+
+        // Assert.
+        assert!(usdc.role_offers(None, None).is_empty());
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_offer_expired\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\",\"expired_at\":\"{}\"}}}}", Role::Pauser, accounts(2), ROLE_OFFER_VALIDITY_PERIOD_NANOSECONDS)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: role offer has not expired")]
+    fn test_remove_expired_role_offer_not_expired() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), None);
+
+        // Act.
+        usdc.remove_expired_role_offer(Role::Pauser, accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Pauser")]
+    fn test_time_bounded_role_expired() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), Some(U64::from(1000)));
+        set_caller(accounts(2));
+        usdc.accept_role(Role::Pauser);
+        assert_eq!(
+            usdc.role_expires_at(accounts(2), Role::Pauser),
+            Some(U64::from(1000))
+        );
+        let mut context: VMContextBuilder = set_caller(accounts(2));
+        context.block_timestamp(999);
+        testing_env!(context.build());
+        usdc.set_pause_flags(vec![PausableOperation::Mint]);
+
+        // Act.
+        context.block_timestamp(1000);
+        testing_env!(context.build());
+        usdc.unpause();
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: role expiry must be in the future")]
+    fn test_configure_controller_expiry_in_past() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let mut context: VMContextBuilder = set_caller(master_minter());
+        context.block_timestamp(1000);
+        testing_env!(context.build());
+
+        // Act.
+        usdc.configure_controller(accounts(2), accounts(3), Some(U64::from(1000)));
+    }
+
+    #[test]
+    fn test_configure_multisig_role_until() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let owner2: AccountId = "owner2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, owner2.clone());
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::ConfigureMultisigRoleUntil {
+                role: Role::Pauser,
+                account_id: accounts(2),
+                expires_at: U64::from(1000),
+            },
+            None,
+            None,
+        );
+        usdc.approve_multisig_request(request_id);
+        set_caller(owner2);
+        usdc.approve_multisig_request(request_id);
+        usdc.execute_multisig_request(request_id);
+
+        // Act.
+        set_caller(accounts(2));
+        usdc.accept_role(Role::Pauser);
+
+        // Assert.
+        assert_eq!(
+            usdc.role_expires_at(accounts(2), Role::Pauser),
+            Some(U64::from(1000))
+        );
+    }
+
+    #[test]
+    fn test_configure_controller_until() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let master_minter2: AccountId = "master_minter2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::MasterMinter, master_minter2.clone());
+        set_caller(master_minter());
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::ConfigureControllerUntil {
+                controller_id: accounts(3),
+                minter_id: accounts(4),
+                expires_at: U64::from(2000),
+            },
+            None,
+            None,
+        );
+        usdc.approve_multisig_request(request_id);
+        set_caller(master_minter2);
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        usdc.execute_multisig_request(request_id);
+
+        // Assert.
+        assert_eq!(usdc.controllers.get(&accounts(3)), Some(&accounts(4)));
+        assert_eq!(
+            usdc.role_expires_at(accounts(3), Role::Controller),
+            Some(U64::from(2000))
+        );
+    }
+
+    #[test]
+    fn test_prune_expired_roles() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Pauser, accounts(1));
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), Some(U64::from(1000)));
+        set_caller(accounts(2));
+        usdc.accept_role(Role::Pauser);
+        set_caller(master_minter());
+        usdc.configure_controller(accounts(3), accounts(4), Some(U64::from(2000)));
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(accounts(5));
+        context.block_timestamp(1000);
+        testing_env!(context.build());
+        let pruned_count: u32 = usdc.prune_expired_roles(None, None);
+
+        // Assert.
+        assert_eq!(pruned_count, 1);
+        assert!(!usdc.pausers().contains(&accounts(2)));
+        assert_eq!(usdc.role_expires_at(accounts(2), Role::Pauser), None);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"role_revoked\",\"data\":{{\"role\":\"{}\",\"account_id\":\"{}\"}}}}", Role::Pauser, accounts(2))
+        );
+
+        // Act.
+        context.block_timestamp(2000);
+        testing_env!(context.build());
+        let pruned_count: u32 = usdc.prune_expired_roles(None, None);
+
+        // Assert.
+        assert_eq!(pruned_count, 1);
+        assert!(!<Contract as Rbac>::has_role(
+            &accounts(3),
+            &Role::Controller
+        ));
+        assert_eq!(usdc.controllers.get(&accounts(3)), None);
+        assert_eq!(usdc.prune_expired_roles(None, None), 0);
+    }
+
+    #[test]
+    fn test_prune_expired_roles_keeps_min_role_members() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), Some(U64::from(1000)));
+        set_caller(accounts(2));
+        usdc.accept_role(Role::Pauser);
+
+        // Act.
+        set_caller_at(accounts(5), 1000);
+        let pruned_count: u32 = usdc.prune_expired_roles(None, None);
+
+        // Assert.
+        assert_eq!(pruned_count, 0);
+        assert!(usdc.pausers().contains(&accounts(2)));
+        assert_eq!(
+            usdc.role_expires_at(accounts(2), Role::Pauser),
+            Some(U64::from(1000))
+        );
+    }
+
+    #[test]
+    fn test_prune_expired_roles_keeps_multisig_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(master_minter());
+        usdc.configure_controller(accounts(3), accounts(4), Some(U64::from(2000)));
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Pauser, accounts(3));
+
+        // Act.
+        set_caller_at(accounts(5), 2000);
+        let pruned_count: u32 = usdc.prune_expired_roles(None, None);
+
+        // Assert.
+        assert_eq!(pruned_count, 1);
+        assert!(!<Contract as Rbac>::has_role(
+            &accounts(3),
+            &Role::Controller
+        ));
+        assert!(<Contract as Rbac>::has_role(&accounts(3), &Role::Pauser));
+        assert!(<Contract as Rbac>::has_role(&accounts(3), &Role::Multisig));
+    }
+
+    #[test]
+    fn test_prune_expired_roles_paginated() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(master_minter());
+        usdc.configure_controller(accounts(3), accounts(4), Some(U64::from(2000)));
+
+        // Act.
+        set_caller_at(accounts(5), 2000);
+        let first_page_pruned_count: u32 = usdc.prune_expired_roles(Some(0), Some(2));
+        let second_page_pruned_count: u32 = usdc.prune_expired_roles(Some(2), Some(2));
+
+        // Assert.
+        assert_eq!(first_page_pruned_count, 0);
+        assert_eq!(second_page_pruned_count, 1);
+        assert_eq!(usdc.controllers.get(&accounts(3)), None);
+    }
+
+    #[test]
+    fn test_revoke_multisig_role_removes_role_offer() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        usdc.configure_multisig_role(Role::Pauser, accounts(2), None);
+
+        // Act.
+        usdc.revoke_multisig_role(Role::Pauser, accounts(2));
+
+        // Assert.
+        assert!(usdc.role_offers(None, None).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "RemovalNotAllowed(RequestStillValid)")]
+    fn test_remove_multisig_request() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_master_minter_id: AccountId = "new_master_minter".parse().unwrap();
+        let request_id: u32 = usdc.get_next_multisig_request_id();
+        let update_master_minter_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
+            role: Role::MasterMinter,
+            account_id: new_master_minter_id.clone(),
+        };
+        set_caller(owner());
+        usdc.create_multisig_request(update_master_minter_action, None, None);
+
+        // Act.
+        usdc.remove_multisig_request(request_id);
+    }
+
+    #[test]
+    fn test_remove_expired_multisig_request() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+        let request_id: u32 = usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        let mut context: VMContextBuilder = set_caller(pauser());
+        context.block_timestamp(432000000000001);
+        testing_env!(context.build());
+        usdc.remove_multisig_request(request_id);
+
+        // Assert.
+        assert!(usdc.get_multisig_request(request_id).is_none());
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_expired\",\"data\":{{\"request_id\":{},\"expired_at\":\"432000000000000\"}}}}", request_id)
+        );
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_removed\",\"data\":{{\"request_id\":{},\"account_id\":\"{}\",\"approval_count\":1,\"action\":\"Pause\"}}}}", request_id, pauser())
+        );
+    }
+
+    #[test]
+    fn test_multisig_request_lifecycle_events() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let pauser2: AccountId = "pauser2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Pauser, pauser2.clone());
+        set_caller(pauser());
+        let request_id: u32 = usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        set_caller(pauser2.clone());
+        usdc.approve_multisig_request(request_id);
+        usdc.execute_multisig_request(request_id);
+
+        // Assert.
+        let logs: Vec<String> = test_utils::get_logs();
+        assert_eq!(
+            logs[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_approved\",\"data\":{{\"request_id\":{},\"account_id\":\"{}\",\"approval_count\":2,\"action\":\"Pause\"}}}}", request_id, pauser2)
+        );
+        assert_eq!(
+            logs[1],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"2.0.0\",\"event\":\"paused\",\"data\":{\"operations\":[\"Transfer\",\"TransferCall\",\"Mint\",\"Burn\",\"Approve\",\"TransferFrom\",\"Storage\"]}}"
+        );
+        assert_eq!(
+            logs[2],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_executed\",\"data\":{{\"request_id\":{},\"account_id\":\"{}\",\"approval_count\":2,\"action\":\"Pause\"}}}}", request_id, pauser2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Owner")]
+    fn test_create_multisig_request_missing_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_master_minter_id: AccountId = "new_master_minter".parse().unwrap();
+        let update_master_minter_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
+            role: Role::MasterMinter,
+            account_id: new_master_minter_id.clone(),
+        };
+        usdc._grant_multisig_role(new_master_minter_id, &Role::Controller);
+
+        // Act.
+        usdc.create_multisig_request(update_master_minter_action, None, None);
+    }
+
+    #[test]
+    fn test_create_multisig_request_with_description() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::Pause,
+            Some("INC-1234: suspicious mint".to_string()),
+            Some(Base64VecU8::from(vec![1, 2, 3])),
+        );
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{},\"creator_id\":\"{}\",\"action\":\"Pause\",\"description\":\"INC-1234: suspicious mint\",\"reference_hash\":\"AQID\"}}}}", request_id, pauser())
+        );
+        let request: MultisigRequestView = usdc.get_multisig_request(request_id).unwrap();
+        assert_eq!(
+            request.description,
+            Some("INC-1234: suspicious mint".to_string())
+        );
+        assert_eq!(
+            request.reference_hash,
+            Some(Base64VecU8::from(vec![1, 2, 3]))
+        );
+
+        // The description and reference hash are removed along with the request.
+        let mut context: VMContextBuilder = set_caller(pauser());
+        context.block_timestamp(432000000000001);
+        testing_env!(context.build());
+        usdc.remove_multisig_request(request_id);
+        assert!(usdc.multisig_request_metadata.get(&request_id).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: description cannot be longer than 256 bytes")]
+    fn test_create_multisig_request_description_too_long() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        usdc.create_multisig_request(FiatTokenAction::Pause, Some("a".repeat(257)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: reference hash must be between 1 and 64 bytes")]
+    fn test_create_multisig_request_reference_hash_too_long() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        usdc.create_multisig_request(
+            FiatTokenAction::Pause,
+            None,
+            Some(Base64VecU8::from(vec![0; 65])),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: ExecutionEligibility(InsufficientApprovals { current: 1, required: 2 })"
+    )]
+    fn test_multisig_update_blocklister_insufficient_approvals() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_blocklister_id: AccountId = "new_blocklister".parse().unwrap();
+        let update_blocklister_action: FiatTokenAction = FiatTokenAction::UpdateBlocklister {
+            new_blocklister_id: new_blocklister_id.clone(),
+        };
+        set_caller(owner());
+        let owner2: AccountId = "owner2".parse().unwrap();
+        usdc.configure_multisig_role(Role::Owner, owner2, None);
+
+        // Act.
+        let update_blocklister_request_id: u32 =
+            usdc.create_multisig_request(update_blocklister_action, None, None);
+        usdc.approve_multisig_request(update_blocklister_request_id.clone());
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{},\"creator_id\":\"{}\",\"action\":{{\"UpdateBlocklister\":{{\"new_blocklister_id\":\"{}\"}}}},\"description\":null,\"reference_hash\":null}}}}", update_blocklister_request_id.to_string(), owner(), new_blocklister_id)
+        );
+        usdc.execute_multisig_request(update_blocklister_request_id.clone());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: ApprovalError(AlreadyApprovedByAccount)"
+    )]
+    fn test_multisig_update_blocklister_same_approver() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_blocklister_id: AccountId = "new_blocklister".parse().unwrap();
+        let update_blocklister_action: FiatTokenAction = FiatTokenAction::UpdateBlocklister {
+            new_blocklister_id: new_blocklister_id.clone(),
+        };
+        set_caller(owner());
+
+        // Act.
+        let update_blocklister_request_id: u32 =
+            usdc.create_multisig_request(update_blocklister_action, None, None);
+        usdc.approve_multisig_request(update_blocklister_request_id);
+        usdc.approve_multisig_request(update_blocklister_request_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Owner")]
+    fn test_multisig_configure_pauser_grant_then_revoke_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let new_pauser_id: AccountId = "new_pauser".parse().unwrap();
+        let pauser2: AccountId = "pauser2".parse().unwrap();
+        set_caller(pauser());
+        let configure_pauser_action: FiatTokenAction = FiatTokenAction::ConfigureMultisigRole {
+            role: Role::Pauser,
+            account_id: new_pauser_id.clone(),
+        };
+
+        // Act.
+        set_caller(pauser());
+        let configure_pauser_request_id: u32 =
+            usdc.create_multisig_request(configure_pauser_action, None, None);
+        usdc.approve_multisig_request(configure_pauser_request_id);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{},\"creator_id\":\"{}\",\"action\":{{\"ConfigureMultisigRole\":{{\"role\":\"Pauser\",\"account_id\":\"{}\"}}}},\"description\":null,\"reference_hash\":null}}}}", configure_pauser_request_id.to_string(), pauser(), new_pauser_id)
+        );
+        // Grant the account the Multisig and Owner Role but then revoke it.
+        usdc._revoke_multisig_role(&pauser2, &Role::Pauser);
+        set_caller(pauser2);
+        usdc.approve_multisig_request(configure_pauser_request_id.clone());
+    }
+
+    #[test]
+    fn test_multisig_configure_minter_allowance() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let minter2: AccountId = "minter2".parse().unwrap();
+        let controller2a: AccountId = "controller2a".parse().unwrap();
+        let controller2b: AccountId = "controller2b".parse().unwrap();
+        set_caller(master_minter());
+        usdc.configure_controller(controller2a.clone(), minter2.clone(), None);
+        usdc.configure_controller(controller2b.clone(), minter2.clone(), None);
+        let configure_minter_allowance_action: FiatTokenAction =
+            FiatTokenAction::ConfigureMinterAllowance {
+                controller_id: controller2a.clone(),
+                minter_allowance: U128::from(12345),
+            };
+        set_caller(controller2a.clone());
+
+        // Act.
+        let configure_minter_allowance_request_id: u32 =
+            usdc.create_multisig_request(configure_minter_allowance_action, None, None);
+        usdc.approve_multisig_request(configure_minter_allowance_request_id);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"multisig_request_created\",\"data\":{{\"request_id\":{},\"creator_id\":\"{}\",\"action\":{{\"ConfigureMinterAllowance\":{{\"controller_id\":\"{}\",\"minter_allowance\":\"12345\"}}}},\"description\":null,\"reference_hash\":null}}}}", configure_minter_allowance_request_id.to_string(), controller2a, controller2a)
+        );
+        set_caller(controller2b);
+        usdc.approve_multisig_request(configure_minter_allowance_request_id.clone());
+        usdc.execute_multisig_request(configure_minter_allowance_request_id.clone());
+
+        // Assert.
+        assert!(usdc.is_minter(&minter2));
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_configured\",\"data\":{{\"minter_id\":\"{}\",\"minter_allowance\":\"12345\"}}}}", minter2.to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "FiatToken: can only approve requests to configure the allowance of your own minter"
+    )]
+    fn test_multisig_only_approve_requests_to_configure_own_minter() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let minter2: AccountId = "minter2".parse().unwrap();
+        let controller2: AccountId = "controller2".parse().unwrap();
+        set_caller(master_minter());
+        usdc.configure_controller(controller2.clone(), minter2.clone(), None);
+        let configure_minter_allowance_action: FiatTokenAction =
+            FiatTokenAction::ConfigureMinterAllowance {
+                controller_id: controller2.clone(),
+                minter_allowance: U128::from(12345),
+            };
+        set_caller(controller());
+
+        // Act.
+        // Attempt to approve (as controller2) a request for configuring controller's minter.
+        let configure_minter_allowance_request_id: u32 =
+            usdc.create_multisig_request(configure_minter_allowance_action, None, None);
+        usdc.approve_multisig_request(configure_minter_allowance_request_id);
+    }
+
+    #[test]
+    fn test_multisig_decrease_minter_allowance() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let minter2: AccountId = "minter2".parse().unwrap();
+        let controller2a: AccountId = "controller2a".parse().unwrap();
         let controller2b: AccountId = "controller2b".parse().unwrap();
         set_caller(master_minter());
-        usdc.configure_controller(controller2a.clone(), minter2.clone());
-        usdc.configure_controller(controller2b.clone(), minter2.clone());
-        let dec_minter_allowance_action: FiatTokenAction =
-            FiatTokenAction::DecreaseMinterAllowance {
-                controller_id: controller2b.clone(),
-                decrement: U128(50),
-            };
+        usdc.configure_controller(controller2a.clone(), minter2.clone(), None);
+        usdc.configure_controller(controller2b.clone(), minter2.clone(), None);
+        let dec_minter_allowance_action: FiatTokenAction =
+            FiatTokenAction::DecreaseMinterAllowance {
+                controller_id: controller2b.clone(),
+                decrement: U128(50),
+            };
+
+        // Act.
+        set_caller(controller2b);
+        usdc.configure_minter_allowance(U128::from(51));
+        let dec_minter_allowance_request_id: u32 =
+            usdc.create_multisig_request(dec_minter_allowance_action, None, None);
+        usdc.approve_multisig_request(dec_minter_allowance_request_id);
+
+        set_caller(controller2a.clone());
+        usdc.approve_multisig_request(dec_minter_allowance_request_id.clone());
+        usdc.execute_multisig_request(dec_minter_allowance_request_id.clone());
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_configured\",\"data\":{{\"minter_id\":\"{}\",\"minter_allowance\":{}}}}}",
+                    minter2.to_string(),
+                    &near_sdk::serde_json::to_string(&U128::from(1)).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_revoke_multisig_approval() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+        let pause_request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(pause_request_id);
+
+        // Act.
+        usdc.revoke_multisig_approval(pause_request_id);
+
+        // Assert.
+        assert!(usdc
+            .get_multisig_request(pause_request_id)
+            .unwrap()
+            .approved_by
+            .is_empty());
+        assert_eq!(
+            test_utils::get_logs()[2],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_approval_revoked\",\"data\":{{\"request_id\":{},\"account_id\":\"{}\",\"approval_count\":0,\"action\":\"Pause\"}}}}",
+                    pause_request_id,
+                    pauser())
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: ExecutionEligibility(InsufficientApprovals { current: 1, required: 2 })"
+    )]
+    fn test_revoke_multisig_approval_rechecks_threshold() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let pauser2: AccountId = "pauser2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Pauser, pauser2.clone());
+        set_caller(pauser());
+        let pause_request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(pause_request_id);
+        set_caller(pauser2.clone());
+        usdc.approve_multisig_request(pause_request_id);
+        usdc.revoke_multisig_approval(pause_request_id);
+
+        // Act.
+        usdc.execute_multisig_request(pause_request_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller has not approved the request")]
+    fn test_revoke_multisig_approval_not_approved() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+        let pause_request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+
+        // Act.
+        usdc.revoke_multisig_approval(pause_request_id);
+    }
+
+    #[test]
+    fn test_multisig_batch() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let owner2: AccountId = "owner2".parse().unwrap();
+        let controller2: AccountId = "controller2".parse().unwrap();
+        let minter2: AccountId = "minter2".parse().unwrap();
+        let new_pauser_id: AccountId = "new_pauser".parse().unwrap();
+        let master_minter2: AccountId = "master_minter2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, owner2.clone());
+        configure_and_accept_role(&mut usdc, Role::MasterMinter, master_minter2.clone());
+        let batch_action: FiatTokenAction = FiatTokenAction::Batch(BatchActions(vec![
+            FiatTokenAction::ConfigureController {
+                controller_id: controller2.clone(),
+                minter_id: minter2.clone(),
+            },
+            FiatTokenAction::ConfigureMultisigRole {
+                role: Role::Pauser,
+                account_id: new_pauser_id.clone(),
+            },
+        ]));
+        set_caller(owner());
+        let request_id: u32 = usdc.create_multisig_request(batch_action, None, None);
+        usdc.approve_multisig_request(request_id);
+        let request: MultisigRequestView = usdc.get_multisig_request(request_id).unwrap();
+        assert_eq!(request.role_required, Role::Owner);
+        assert_eq!(
+            request.roles_required,
+            vec![Role::MasterMinter, Role::Owner]
+        );
+
+        // Act.
+        set_caller(owner2);
+        usdc.approve_multisig_request(request_id);
+        set_caller(master_minter());
+        usdc.approve_multisig_request(request_id);
+        set_caller(master_minter2);
+        usdc.approve_multisig_request(request_id);
+        usdc.execute_multisig_request(request_id);
+        set_caller(new_pauser_id.clone());
+        usdc.accept_role(Role::Pauser);
+
+        // Assert.
+        assert_eq!(usdc.controllers.get(&controller2), Some(&minter2));
+        assert!(usdc.pausers().contains(&new_pauser_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "InsufficientApprovals { current: 1, required: 2 }")]
+    fn test_multisig_batch_insufficient_role_approvals() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let owner2: AccountId = "owner2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, owner2.clone());
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::Batch(BatchActions(vec![
+                FiatTokenAction::ConfigureController {
+                    controller_id: accounts(2),
+                    minter_id: accounts(3),
+                },
+                FiatTokenAction::ConfigureMultisigRole {
+                    role: Role::Pauser,
+                    account_id: accounts(4),
+                },
+            ])),
+            None,
+            None,
+        );
+        usdc.approve_multisig_request(request_id);
+        set_caller(owner2);
+        usdc.approve_multisig_request(request_id);
+        set_caller(master_minter());
+        usdc.approve_multisig_request(request_id);
 
         // Act.
-        set_caller(controller2b);
-        usdc.configure_minter_allowance(U128::from(51));
-        let dec_minter_allowance_request_id: u32 =
-            usdc.create_multisig_request(dec_minter_allowance_action);
-        usdc.approve_multisig_request(dec_minter_allowance_request_id);
+        usdc.execute_multisig_request(request_id);
+    }
 
-        set_caller(controller2a.clone());
-        usdc.approve_multisig_request(dec_minter_allowance_request_id.clone());
-        usdc.execute_multisig_request(dec_minter_allowance_request_id.clone());
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Owner or Pauser")]
+    fn test_multisig_batch_missing_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(master_minter());
+
+        // Act.
+        usdc.create_multisig_request(
+            FiatTokenAction::Batch(BatchActions(vec![
+                FiatTokenAction::Pause,
+                FiatTokenAction::UpdateBlocklister {
+                    new_blocklister_id: pauser(),
+                },
+            ])),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: batch cannot contain more than 10 actions")]
+    fn test_multisig_batch_too_large() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        usdc.create_multisig_request(
+            FiatTokenAction::Batch(BatchActions(
+                (0..11).map(|_| FiatTokenAction::Pause).collect(),
+            )),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: batches cannot be nested")]
+    fn test_multisig_batch_nested() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+
+        // Act.
+        usdc.create_multisig_request(
+            FiatTokenAction::Batch(BatchActions(vec![FiatTokenAction::Batch(BatchActions(
+                vec![FiatTokenAction::Pause],
+            ))])),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_multisig_timelock() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let admin2: AccountId = "admin2".parse().unwrap();
+        set_caller(admin());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin2.clone());
+        usdc.update_multisig_timelock(U64::from(1000));
+        let request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::ApproveForUpgrade, None, None);
+        usdc.approve_multisig_request(request_id);
+        assert_eq!(
+            usdc.get_multisig_request(request_id).unwrap().executable_at,
+            None
+        );
+
+        // Act.
+        set_caller(admin2.clone());
+        usdc.approve_multisig_request(request_id);
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_timelock_started\",\"data\":{{\"request_id\":{},\"executable_at\":\"1000\"}}}}", request_id)
+        );
+        let request: MultisigRequestView = usdc.get_multisig_request(request_id).unwrap();
+        assert_eq!(request.executable_at, Some(U64::from(1000)));
+        assert!(!request.is_executable);
+
+        // The request can be executed once the timelock has elapsed.
+        set_caller_at(admin2, 1000);
+        assert!(usdc.get_multisig_request(request_id).unwrap().is_executable);
+        usdc.execute_multisig_request(request_id);
+        assert!(usdc.approved_for_upgrade);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: multisig request timelock not started")]
+    fn test_multisig_timelock_not_started() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.update_multisig_timelock(U64::from(1000));
+        let request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::ApproveForUpgrade, None, None);
+        usdc.approve_multisig_request(request_id);
+        usdc.update_multisig_configuration(Some(Role::Admin), 1, U64::from(432000000000000));
+
+        // Act.
+        set_caller_at(admin(), 1000);
+        usdc.execute_multisig_request(request_id);
+    }
+
+    #[test]
+    fn test_multisig_timelock_started_on_approval_again() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.update_multisig_timelock(U64::from(1000));
+        let request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::ApproveForUpgrade, None, None);
+        usdc.approve_multisig_request(request_id);
+        usdc.update_multisig_configuration(Some(Role::Admin), 1, U64::from(432000000000000));
+        usdc.revoke_multisig_approval(request_id);
+
+        // Act.
+        set_caller_at(admin(), 500);
+        usdc.approve_multisig_request(request_id);
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[1],
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_timelock_started\",\"data\":{{\"request_id\":{},\"executable_at\":\"1500\"}}}}", request_id)
+        );
+        assert_eq!(
+            usdc.get_multisig_request(request_id).unwrap().executable_at,
+            Some(U64::from(1500))
+        );
+
+        // The request can be executed once the timelock has elapsed.
+        set_caller_at(admin(), 1500);
+        usdc.execute_multisig_request(request_id);
+        assert!(usdc.approved_for_upgrade);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: multisig request timelock not elapsed until 1000")]
+    fn test_multisig_timelock_not_elapsed() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let admin2: AccountId = "admin2".parse().unwrap();
+        set_caller(admin());
+        usdc.configure_multisig_role(Role::Admin, admin2.clone(), None);
+        usdc.update_multisig_timelock(U64::from(1000));
+        let request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::ApproveForUpgrade, None, None);
+        usdc.approve_multisig_request(request_id);
+        set_caller(admin2.clone());
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        set_caller_at(admin2, 999);
+        usdc.execute_multisig_request(request_id);
+    }
+
+    #[test]
+    fn test_cancel_multisig_request() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let admin2: AccountId = "admin2".parse().unwrap();
+        set_caller(admin());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin2.clone());
+        let request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::ApproveForUpgrade, None, None);
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        set_caller(admin2.clone());
+        usdc.cancel_multisig_request(request_id);
 
         // Assert.
+        assert!(usdc.get_multisig_request(request_id).is_none());
         assert_eq!(
             test_utils::get_logs()[0],
-            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"minter_configured\",\"data\":{{\"minter_id\":\"{}\",\"minter_allowance\":{}}}}}",
-                    minter2.to_string(),
-                    &near_sdk::serde_json::to_string(&U128::from(1)).unwrap())
+            format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_request_cancelled\",\"data\":{{\"request_id\":{},\"account_id\":\"{}\",\"approval_count\":1,\"action\":\"ApproveForUpgrade\"}}}}", request_id, admin2)
         );
     }
 
+    #[test]
+    #[should_panic(expected = "FiatToken: only timelocked multisig requests can be cancelled")]
+    fn test_cancel_multisig_request_not_timelocked() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+        let request_id: u32 = usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+
+        // Act.
+        usdc.cancel_multisig_request(request_id);
+    }
+
     #[test]
     fn test_get_next_multisig_request_id() {
         // Arrange.
@@ -2384,7 +6257,8 @@ mod tests {
         assert_eq!(usdc.get_next_multisig_request_id(), 0);
 
         // Act.
-        let update_pauser_request_id: u32 = usdc.create_multisig_request(update_pauser_action);
+        let update_pauser_request_id: u32 =
+            usdc.create_multisig_request(update_pauser_action, None, None);
 
         // Assert.
         assert_eq!(update_pauser_request_id, 0);
@@ -2392,6 +6266,285 @@ mod tests {
         assert_eq!(usdc.get_next_multisig_request_id(), 1);
     }
 
+    #[test]
+    fn test_get_multisig_request() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let owner2: AccountId = "owner2".parse().unwrap();
+        let new_pauser_id: AccountId = "new_pauser".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, owner2.clone());
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::ConfigureMultisigRole {
+                role: Role::Pauser,
+                account_id: new_pauser_id.clone(),
+            },
+            None,
+            None,
+        );
+
+        // Act.
+        usdc.approve_multisig_request(request_id);
+        let request: MultisigRequestView = usdc.get_multisig_request(request_id).unwrap();
+
+        // Assert.
+        assert_eq!(request.request_id, request_id);
+        assert!(matches!(
+            request.action,
+            FiatTokenAction::ConfigureMultisigRole { role: Role::Pauser, account_id, .. } if account_id == new_pauser_id
+        ));
+        assert_eq!(request.role_required, Role::Owner);
+        assert_eq!(request.roles_required, vec![Role::Owner]);
+        assert_eq!(request.approved_by, vec![owner()]);
+        assert_eq!(request.created_at, U64::from(0));
+        assert_eq!(request.expires_at, Some(U64::from(432000000000000)));
+        assert!(!request.is_executable);
+
+        // Reaching the approval threshold makes the request executable.
+        set_caller(owner2.clone());
+        usdc.approve_multisig_request(request_id);
+        let request: MultisigRequestView = usdc.get_multisig_request(request_id).unwrap();
+        assert_eq!(request.approved_by, vec![owner(), owner2]);
+        assert!(request.is_executable);
+
+        // Executed requests can no longer be retrieved.
+        usdc.execute_multisig_request(request_id);
+        assert!(usdc.get_multisig_request(request_id).is_none());
+    }
+
+    #[test]
+    fn test_list_multisig_requests() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        let owner2: AccountId = "owner2".parse().unwrap();
+        set_caller(owner());
+        configure_and_accept_role(&mut usdc, Role::Owner, owner2.clone());
+        for pauser_id in ["pauser_a", "pauser_b", "pauser_c"] {
+            usdc.create_multisig_request(
+                FiatTokenAction::ConfigureMultisigRole {
+                    role: Role::Pauser,
+                    account_id: pauser_id.parse().unwrap(),
+                },
+                None,
+                None,
+            );
+        }
+        // Execute the first request so that it no longer shows up as pending.
+        usdc.approve_multisig_request(0);
+        set_caller(owner2);
+        usdc.approve_multisig_request(0);
+        usdc.execute_multisig_request(0);
+
+        // Act & Assert.
+        let request_ids = |requests: Vec<MultisigRequestView>| -> Vec<u32> {
+            requests.iter().map(|request| request.request_id).collect()
+        };
+        assert_eq!(
+            request_ids(usdc.list_multisig_requests(None, None)),
+            vec![1, 2]
+        );
+        assert_eq!(
+            request_ids(usdc.list_multisig_requests(Some(2), None)),
+            vec![2]
+        );
+        assert_eq!(
+            request_ids(usdc.list_multisig_requests(None, Some(1))),
+            vec![1]
+        );
+        assert!(usdc.list_multisig_requests(Some(3), None).is_empty());
+    }
+
+    #[test]
+    fn test_list_multisig_requests_bounded_scan() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+        <Contract as ApprovalManagerInternal<_, _, _>>::slot_next_request_id()
+            .write(&MAX_MULTISIG_REQUEST_IDS_SCANNED);
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::ConfigureMultisigRole {
+                role: Role::Pauser,
+                account_id: accounts(2),
+            },
+            None,
+            None,
+        );
+
+        // Act & Assert.
+        assert!(usdc.list_multisig_requests(None, None).is_empty());
+        assert_eq!(
+            usdc.list_multisig_requests(Some(MAX_MULTISIG_REQUEST_IDS_SCANNED), None)[0].request_id,
+            request_id
+        );
+    }
+
+    #[test]
+    fn test_update_multisig_configuration() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_multisig_configuration(None, 1, U64::from(86400000000000));
+
+        // Assert.
+        let config: Configuration<Contract> = <Contract as ApprovalManager<_, _, _>>::get_config();
+        assert_eq!(config.threshold, 1);
+        assert_eq!(config.validity_period_nanoseconds, 86400000000000);
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_configuration_updated\",\"data\":{\"role\":null,\"old_threshold\":2,\"new_threshold\":1,\"old_validity_period_nanoseconds\":\"432000000000000\",\"new_validity_period_nanoseconds\":\"86400000000000\"}}"
+        );
+
+        // A single approval is now sufficient to execute a request.
+        set_caller(pauser());
+        let pause_request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(pause_request_id);
+        usdc.execute_multisig_request(pause_request_id);
+        assert!(usdc.is_paused());
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: multisig threshold must be at least 1")]
+    fn test_update_multisig_configuration_zero_threshold() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_multisig_configuration(None, 0, U64::from(432000000000000));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: multisig threshold exceeds the number of Owner accounts")]
+    fn test_update_multisig_configuration_threshold_exceeds_members() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.configure_multisig_role(Role::Admin, "admin2".parse().unwrap(), None);
+        usdc._grant_multisig_role("masterminter2".parse().unwrap(), &Role::MasterMinter);
+
+        // Act.
+        // There are 2 Admins and 2 MasterMinters, but only a single Owner.
+        usdc.update_multisig_configuration(None, 2, U64::from(432000000000000));
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: caller is not a Admin")]
+    fn test_update_multisig_configuration_not_admin() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(owner());
+
+        // Act.
+        usdc.update_multisig_configuration(None, 1, U64::from(432000000000000));
+    }
+
+    #[test]
+    fn test_update_multisig_configuration_for_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_multisig_configuration(Some(Role::Pauser), 1, U64::from(86400000000000));
+
+        // Assert.
+        assert_eq!(
+            test_utils::get_logs()[0],
+            "EVENT_JSON:{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"multisig_configuration_updated\",\"data\":{\"role\":\"Pauser\",\"old_threshold\":2,\"new_threshold\":1,\"old_validity_period_nanoseconds\":\"432000000000000\",\"new_validity_period_nanoseconds\":\"86400000000000\"}}"
+        );
+        // The default configuration is left untouched.
+        let config: Configuration<Contract> = <Contract as ApprovalManager<_, _, _>>::get_config();
+        assert_eq!(config.threshold, 2);
+
+        // A single Pauser approval is now sufficient to execute a Pause request...
+        set_caller(pauser());
+        let pause_request_id: u32 =
+            usdc.create_multisig_request(FiatTokenAction::Pause, None, None);
+        usdc.approve_multisig_request(pause_request_id);
+        assert_eq!(
+            usdc.get_multisig_request(pause_request_id)
+                .unwrap()
+                .expires_at,
+            Some(U64::from(86400000000000))
+        );
+        usdc.execute_multisig_request(pause_request_id);
+        assert!(usdc.is_paused());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: ExecutionEligibility(InsufficientApprovals { current: 1, required: 2 })"
+    )]
+    fn test_update_multisig_configuration_for_role_keeps_other_roles() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.update_multisig_configuration(Some(Role::Pauser), 1, U64::from(432000000000000));
+        set_caller(owner());
+        let request_id: u32 = usdc.create_multisig_request(
+            FiatTokenAction::UpdateBlocklister {
+                new_blocklister_id: "new_blocklister".parse().unwrap(),
+            },
+            None,
+            None,
+        );
+        usdc.approve_multisig_request(request_id);
+
+        // Act.
+        // Owner requests still require the default 2 approvals.
+        usdc.execute_multisig_request(request_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "FiatToken: cannot configure multisig for the specified role")]
+    fn test_update_multisig_configuration_for_invalid_role() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+
+        // Act.
+        usdc.update_multisig_configuration(Some(Role::Minter), 1, U64::from(432000000000000));
+    }
+
+    #[test]
+    fn test_get_multisig_configurations() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(admin());
+        usdc.update_multisig_configuration(Some(Role::Controller), 1, U64::from(0));
+
+        // Act.
+        let configs: Vec<MultisigConfigurationView> = usdc.get_multisig_configurations();
+
+        // Assert.
+        let summary: Vec<(String, u8, u64, bool, u32, u32)> = configs
+            .iter()
+            .map(|config| {
+                (
+                    config.role.to_string(),
+                    config.threshold,
+                    config.validity_period_nanoseconds.0,
+                    config.is_default,
+                    config.member_count,
+                    config.min_member_count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Admin".to_string(), 2, 432000000000000, true, 1, 2),
+                ("Controller".to_string(), 1, 0, false, 1, 0),
+                ("MasterMinter".to_string(), 2, 432000000000000, true, 1, 2),
+                ("Owner".to_string(), 2, 432000000000000, true, 1, 2),
+                ("Pauser".to_string(), 2, 432000000000000, true, 1, 2),
+            ]
+        );
+    }
+
     #[test]
     fn test_approve_for_upgrade() {
         // Arrange.
@@ -2399,11 +6552,11 @@ mod tests {
         let approve_for_upgrade_action: FiatTokenAction = FiatTokenAction::ApproveForUpgrade;
         set_caller(admin());
         let admin2: AccountId = "admin2".parse().unwrap();
-        usdc.configure_multisig_role(Role::Admin, admin2.clone());
+        configure_and_accept_role(&mut usdc, Role::Admin, admin2.clone());
 
         // Act.
         let approve_for_upgrade_action_request_id: u32 =
-            usdc.create_multisig_request(approve_for_upgrade_action);
+            usdc.create_multisig_request(approve_for_upgrade_action, None, None);
         usdc.approve_multisig_request(approve_for_upgrade_action_request_id);
         set_caller(admin2);
         usdc.approve_multisig_request(approve_for_upgrade_action_request_id.clone());
@@ -2411,10 +6564,30 @@ mod tests {
 
         // Assert.
         assert_eq!(
-            test_utils::get_logs()[0],
+            test_utils::get_logs()[1],
             format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"approved_for_upgrade\",\"data\":null}}")
         );
         assert_eq!(usdc.approved_for_upgrade, true);
+        assert!(usdc.is_approved_for_upgrade());
+    }
+
+    #[test]
+    fn test_contract_status() {
+        // Arrange.
+        let mut usdc: Contract = init_contract();
+        set_caller(pauser());
+        usdc.pause();
+
+        // Act.
+        let status: ContractStatusView = usdc.contract_status();
+
+        // Assert.
+        assert_eq!(status.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(status.paused_operations, PAUSABLE_OPERATIONS.to_vec());
+        assert!(!status.approved_for_upgrade);
+        assert!(!status.allowlist_enabled);
+        assert_eq!(status.multisig_configurations.len(), MULTISIG_ROLES.len());
+        assert_eq!(status.multisig_timelock_nanoseconds, U64::from(0));
     }
 
     #[test]
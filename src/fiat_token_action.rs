@@ -14,19 +14,26 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::role::Role;
+use crate::pausable_operation::PausableOperation;
+use crate::role::{Role, MULTISIG_ROLES_BY_PRIVILEGE};
+use crate::supply_cap_policy::SupplyCapPolicy;
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
-    json_types::U128,
+    json_types::{U128, U64},
+    require,
     serde::{Deserialize, Serialize},
     AccountId,
 };
 
+/// The maximum number of actions a [`FiatTokenAction::Batch`] can contain, so that the gas needed
+/// to execute it stays predictable.
+pub(crate) const MAX_BATCH_SIZE: usize = 10;
+
 /// Defines the types of accepted actions the [`ApprovalManager`]/multi-signature requests can accept.
 /// If a multi-sig request is attempted to be created without an action that conforms to one of
 /// these [`FiatTokenActions`], the request will fail with a Deserialization error.
-#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub enum FiatTokenAction {
     ApproveForUpgrade,
     ConfigureController {
@@ -49,6 +56,7 @@ pub enum FiatTokenAction {
         controller_id: AccountId,
         increment: U128,
     },
+    /// Pauses every [`PausableOperation`].
     Pause,
     RemoveController {
         controller_id: AccountId,
@@ -60,57 +68,242 @@ pub enum FiatTokenAction {
         role: Role,
         account_id: AccountId,
     },
+    /// Replaces every blocklister with a single account.
     UpdateBlocklister {
         new_blocklister_id: AccountId,
     },
+    /// Unpauses every [`PausableOperation`].
     Unpause,
+    UpdateMultisigConfiguration {
+        role: Option<Role>,
+        threshold: u8,
+        validity_period_nanoseconds: U64,
+    },
+    UpdateMultisigTimelock {
+        delay_nanoseconds: U64,
+    },
+    /// Replaces the pairs of [`Role`]s that a single account cannot hold at the same time.
+    UpdateRoleConflicts {
+        role_conflicts: Vec<(Role, Role)>,
+    },
+    /// Pauses exactly the given [`PausableOperation`]s, unpausing the others.
+    SetPauseFlags {
+        paused_operations: Vec<PausableOperation>,
+    },
+    /// Grants the Blocklister [`Role`] to an additional account.
+    AddBlocklister {
+        blocklister_id: AccountId,
+    },
+    /// Revokes the Blocklister [`Role`] from an account. At least one blocklister must remain.
+    RemoveBlocklister {
+        blocklister_id: AccountId,
+    },
+    /// Burns the funds of a blocklisted account, or moves them to the `destination` custody
+    /// account if one is given.
+    WipeBlocklistedFunds {
+        account_id: AccountId,
+        destination: Option<AccountId>,
+    },
+    /// Switches allowlist mode on or off. While it is on, only accounts holding the Allowlisted
+    /// [`Role`] can transfer, receive, mint or register.
+    SetAllowlistMode {
+        enabled: bool,
+    },
+    /// Grants the AllowlistManager [`Role`] to an account.
+    AddAllowlistManager {
+        allowlist_manager_id: AccountId,
+    },
+    /// Revokes the AllowlistManager [`Role`] from an account.
+    RemoveAllowlistManager {
+        allowlist_manager_id: AccountId,
+    },
+    /// Sets the compliance oracle consulted in addition to the local blocklist, or stops consulting
+    /// one if `compliance_oracle` is `None`.
+    UpdateComplianceOracle {
+        compliance_oracle: Option<AccountId>,
+    },
+    /// Makes the minter allowance replenish by `replenish_per_second` every second, up to
+    /// `max_allowance`. A `replenish_per_second` of 0 removes the rate limit, leaving a fixed
+    /// allowance.
+    ConfigureMinterRateLimit {
+        controller_id: AccountId,
+        max_allowance: U128,
+        replenish_per_second: U128,
+    },
+    /// Caps the total supply that minting can reach, or lifts the cap if `max_total_supply` is
+    /// `None`. `policy` defines what happens when minter allowances could exceed the cap.
+    UpdateMaxTotalSupply {
+        max_total_supply: Option<U128>,
+        policy: SupplyCapPolicy,
+    },
+    /// Like [`FiatTokenAction::ConfigureController`], but the Controller [`Role`] grant expires at
+    /// the block timestamp `expires_at`. It is a separate variant so that the Borsh layout of
+    /// `ConfigureController` requests created before the contract was upgraded stays unchanged.
+    ConfigureControllerUntil {
+        controller_id: AccountId,
+        minter_id: AccountId,
+        expires_at: U64,
+    },
+    /// Like [`FiatTokenAction::ConfigureMultisigRole`], but the [`Role`] grant expires at the block
+    /// timestamp `expires_at`. It is a separate variant so that the Borsh layout of
+    /// `ConfigureMultisigRole` requests created before the contract was upgraded stays unchanged.
+    ConfigureMultisigRoleUntil {
+        role: Role,
+        account_id: AccountId,
+        expires_at: U64,
+    },
+    /// Executes several actions in order, atomically: if any of them fails, none are applied.
+    Batch(BatchActions),
+}
+
+/// The actions executed by a [`FiatTokenAction::Batch`], serialized as a plain JSON array.
+/// Borsh's derive macros cannot resolve the recursive `Vec<FiatTokenAction>` field, so its Borsh
+/// (de)serialization is delegated to the inner `Vec` by hand.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BatchActions(pub Vec<FiatTokenAction>);
+
+impl BorshSerialize for BatchActions {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+
+impl BorshDeserialize for BatchActions {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(BatchActions(
+            <Vec<FiatTokenAction> as BorshDeserialize>::deserialize(buf)?,
+        ))
+    }
 }
 
 /// Defines additional information and requirements for each [`FiatTokenAction`].
 impl FiatTokenAction {
-    /// Specifies the [`Role`]s required for each [`FiatTokenAction`].
-    pub(crate) fn role_required(&self) -> Role {
+    /// Specifies the [`Role`]s required for each [`FiatTokenAction`]. A [`FiatTokenAction::Batch`]
+    /// requires every Role needed by the actions it contains.
+    pub(crate) fn roles_required(&self) -> Vec<Role> {
+        match self {
+            FiatTokenAction::Batch(BatchActions(actions)) => {
+                let mut roles: Vec<Role> = actions
+                    .iter()
+                    .flat_map(|action| action.roles_required())
+                    .collect();
+                roles.sort();
+                roles.dedup();
+                roles
+            }
+            _ => vec![self.role_required()],
+        }
+    }
+
+    /// Specifies the most privileged of the [`Role`]s required for the [`FiatTokenAction`], as
+    /// ranked by [`MULTISIG_ROLES_BY_PRIVILEGE`].
+    pub(crate) fn highest_role_required(&self) -> Role {
+        let roles_required: Vec<Role> = self.roles_required();
+        MULTISIG_ROLES_BY_PRIVILEGE
+            .into_iter()
+            .find(|role| roles_required.contains(role))
+            .unwrap_or_else(|| env::panic_str("FiatToken: action does not require a multisig role"))
+    }
+
+    /// Specifies the [`Role`] required for a single (i.e. non-batch) [`FiatTokenAction`].
+    fn role_required(&self) -> Role {
         match self {
             FiatTokenAction::ConfigureMultisigRole { role, .. }
+            | FiatTokenAction::ConfigureMultisigRoleUntil { role, .. }
             | FiatTokenAction::RevokeMultisigRole { role, .. } => match role {
                 Role::Admin => Role::Admin,
                 _ => Role::Owner,
             },
-            FiatTokenAction::ApproveForUpgrade => Role::Admin,
+            FiatTokenAction::ApproveForUpgrade
+            | FiatTokenAction::UpdateMultisigConfiguration { .. }
+            | FiatTokenAction::UpdateMultisigTimelock { .. }
+            | FiatTokenAction::UpdateRoleConflicts { .. } => Role::Admin,
             FiatTokenAction::ConfigureController { .. }
+            | FiatTokenAction::ConfigureControllerUntil { .. }
             | FiatTokenAction::RemoveController { .. } => Role::MasterMinter,
             FiatTokenAction::ConfigureMinterAllowance { .. }
             | FiatTokenAction::DecreaseMinterAllowance { .. }
             | FiatTokenAction::IncreaseMinterAllowance { .. }
-            | FiatTokenAction::RemoveMinter { .. } => Role::Controller,
-            FiatTokenAction::Pause | FiatTokenAction::Unpause => Role::Pauser,
-            FiatTokenAction::UpdateBlocklister { .. } => Role::Owner,
+            | FiatTokenAction::RemoveMinter { .. }
+            | FiatTokenAction::ConfigureMinterRateLimit { .. } => Role::Controller,
+            FiatTokenAction::Pause
+            | FiatTokenAction::Unpause
+            | FiatTokenAction::SetPauseFlags { .. } => Role::Pauser,
+            FiatTokenAction::UpdateBlocklister { .. }
+            | FiatTokenAction::AddBlocklister { .. }
+            | FiatTokenAction::RemoveBlocklister { .. }
+            | FiatTokenAction::WipeBlocklistedFunds { .. }
+            | FiatTokenAction::SetAllowlistMode { .. }
+            | FiatTokenAction::AddAllowlistManager { .. }
+            | FiatTokenAction::RemoveAllowlistManager { .. }
+            | FiatTokenAction::UpdateComplianceOracle { .. }
+            | FiatTokenAction::UpdateMaxTotalSupply { .. } => Role::Owner,
+            FiatTokenAction::Batch(_) => {
+                env::panic_str("FiatToken: batch actions can require multiple roles")
+            }
         }
     }
 
-    /// Returns true if the [`FiatTokenAction`] requires verifying the controller.
-    pub(crate) fn requires_controller_check(&self) -> bool {
-        matches!(
-            self,
-            FiatTokenAction::ConfigureMinterAllowance { .. }
-                | FiatTokenAction::DecreaseMinterAllowance { .. }
-                | FiatTokenAction::IncreaseMinterAllowance { .. }
-                | FiatTokenAction::RemoveMinter { .. }
-        )
-    }
-
-    /// Returns the Controller associated with a Controller-related [`FiatTokenAction`] request.
-    pub(crate) fn controller(&self) -> &AccountId {
+    /// Returns the Controllers associated with the Controller-related [`FiatTokenAction`]s of a
+    /// request, which must belong to the same minter as the caller's Controller.
+    pub(crate) fn controllers(&self) -> Vec<&AccountId> {
         match self {
             FiatTokenAction::ConfigureMinterAllowance { controller_id, .. }
             | FiatTokenAction::DecreaseMinterAllowance { controller_id, .. }
             | FiatTokenAction::IncreaseMinterAllowance { controller_id, .. }
-            | FiatTokenAction::RemoveMinter { controller_id, .. } => controller_id,
-            _ => {
-                env::panic_str(
-                    "FiatToken: can only fetch controller for ConfigureMinterAllowance actions",
-                );
+            | FiatTokenAction::RemoveMinter { controller_id, .. }
+            | FiatTokenAction::ConfigureMinterRateLimit { controller_id, .. } => {
+                vec![controller_id]
+            }
+            FiatTokenAction::Batch(BatchActions(actions)) => actions
+                .iter()
+                .flat_map(|action| action.controllers())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns true if the [`FiatTokenAction`] is sensitive enough that requests for it can only be
+    /// executed once the configured timelock has elapsed after reaching their approval threshold,
+    /// giving other role holders a window to cancel them. The timelock itself is timelocked so
+    /// that it cannot be lifted and bypassed at once.
+    pub(crate) fn is_timelocked(&self) -> bool {
+        match self {
+            FiatTokenAction::ApproveForUpgrade
+            | FiatTokenAction::ConfigureMultisigRole {
+                role: Role::Admin, ..
+            }
+            | FiatTokenAction::ConfigureMultisigRoleUntil {
+                role: Role::Admin, ..
+            }
+            | FiatTokenAction::RevokeMultisigRole { .. }
+            | FiatTokenAction::UpdateMultisigTimelock { .. } => true,
+            FiatTokenAction::Batch(BatchActions(actions)) => {
+                actions.iter().any(|action| action.is_timelocked())
             }
+            _ => false,
+        }
+    }
+
+    /// Verifies that the [`FiatTokenAction`] is well-formed before a request is created for it.
+    /// Batches must contain between 1 and [`MAX_BATCH_SIZE`] actions, and cannot be nested.
+    pub(crate) fn validate(&self) {
+        if let FiatTokenAction::Batch(BatchActions(actions)) = self {
+            require!(
+                !actions.is_empty(),
+                "FiatToken: batch must contain at least one action"
+            );
+            require!(
+                actions.len() <= MAX_BATCH_SIZE,
+                format!("FiatToken: batch cannot contain more than {MAX_BATCH_SIZE} actions")
+            );
+            require!(
+                !actions
+                    .iter()
+                    .any(|action| matches!(action, FiatTokenAction::Batch(_))),
+                "FiatToken: batches cannot be nested"
+            );
         }
     }
 }
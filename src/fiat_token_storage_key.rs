@@ -20,6 +20,8 @@ use near_sdk::{
 };
 /// Defines a set of [`StorageKey`]s for [`UnorderedSet`]'s and [`UnorderedMap`]'s prefixes.
 /// It is used to namespace the collections in the NEAR VM and prevent collisions in this contract.
+/// New keys must be added to the bottom of the enum list, otherwise the prefixes of the existing
+/// collections will change when the contract is migrated.
 #[derive(Debug, Clone, BorshSerialize, near_sdk::BorshStorageKey)]
 pub(crate) enum FiatTokenStorageKey {
     // StorageKey for a temporary UnorderedMap to map a spender_id to its allowed spending amount.
@@ -33,4 +35,12 @@ pub(crate) enum FiatTokenStorageKey {
     FungibleToken,
     Metadata,
     MinterAllowed,
+    MultisigConfigurations,
+    MultisigThresholdReachedAt,
+    MultisigRequestMetadata,
+    RoleOffers,
+    RoleExpirations,
+    BlocklistEntries,
+    OracleBlocklist,
+    MinterRateLimits,
 }
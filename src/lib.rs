@@ -14,10 +14,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod compliance_oracle;
 pub mod fiat_token;
 pub mod role;
 
+mod blocklist_entry;
+mod contract_status;
 mod events;
 mod fiat_token_action;
 mod fiat_token_storage_key;
+mod minter_rate_limit;
+mod multisig_configuration;
+mod multisig_request;
+mod pausable_operation;
 mod requires;
+mod role_expiration;
+mod role_offer;
+mod supply_cap_policy;
@@ -0,0 +1,91 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+};
+
+/// The number of nanoseconds in a second, the unit of block timestamps.
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Read-only representation of the rate limit of a minter's allowance.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MinterRateLimitView {
+    /// Allowance up to which the minter allowance replenishes.
+    pub max_allowance: U128,
+    /// Amount by which the minter allowance replenishes every second.
+    pub replenish_per_second: U128,
+}
+
+/// Token-bucket rate limit of a minter's allowance: the allowance replenishes over time, up to a
+/// maximum, so that a minter can keep a steady issuance without each increase going through a
+/// multi-sig request.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct MinterRateLimit {
+    /// Allowance up to which the minter allowance replenishes.
+    pub max_allowance: u128,
+    /// Amount by which the minter allowance replenishes every second.
+    pub replenish_per_second: u128,
+    /// Block timestamp at which the stored minter allowance was last updated.
+    pub updated_at: u64,
+}
+
+impl MinterRateLimit {
+    /// Returns the given minter allowance, as stored when the rate limit was last updated,
+    /// replenished up to the given block timestamp. An allowance that was configured above the
+    /// maximum is left as is.
+    /// * `allowance`   - The stored minter allowance.
+    /// * `now`         - The current block timestamp.
+    pub fn replenish(&self, allowance: u128, now: u64) -> u128 {
+        if allowance >= self.max_allowance {
+            return allowance;
+        }
+        allowance
+            .saturating_add(self.replenished(now))
+            .min(self.max_allowance)
+    }
+
+    /// Returns the block timestamp up to which the replenishment of the given minter allowance is
+    /// credited by [`MinterRateLimit::replenish`]. Only whole units are replenished, so the time
+    /// towards the next unit is carried over rather than lost when the allowance is stored again.
+    /// * `allowance`   - The stored minter allowance.
+    /// * `now`         - The current block timestamp.
+    pub fn replenished_at(&self, allowance: u128, now: u64) -> u64 {
+        let replenished: u128 = self.replenished(now);
+        if allowance.saturating_add(replenished) >= self.max_allowance {
+            return now;
+        }
+        let replenished_nanoseconds: u128 = replenished * NANOSECONDS_PER_SECOND;
+        let mut credited_nanoseconds: u128 = replenished_nanoseconds / self.replenish_per_second;
+        // Rounded up, so that the allowance never replenishes faster than the rate.
+        if credited_nanoseconds * self.replenish_per_second < replenished_nanoseconds {
+            credited_nanoseconds += 1;
+        }
+        self.updated_at + credited_nanoseconds as u64
+    }
+
+    /// Private function to compute the whole units replenished since the rate limit was last
+    /// updated.
+    /// * `now`         - The current block timestamp.
+    fn replenished(&self, now: u64) -> u128 {
+        let elapsed_nanoseconds: u128 = now.saturating_sub(self.updated_at).into();
+        self.replenish_per_second
+            .saturating_mul(elapsed_nanoseconds)
+            / NANOSECONDS_PER_SECOND
+    }
+}
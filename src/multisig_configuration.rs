@@ -0,0 +1,40 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::role::Role;
+use near_sdk::{
+    json_types::U64,
+    serde::{Deserialize, Serialize},
+};
+
+/// Read-only representation of the multi-sig settings that apply to requests requiring a given
+/// [`Role`].
+#[derive(Serialize, Deserialize)]
+pub struct MultisigConfigurationView {
+    pub role: Role,
+    /// Number of approvals required to execute a request.
+    pub threshold: u8,
+    /// Period after which a request can no longer be executed, and can be removed.
+    /// 0 = perpetual validity, no deletion.
+    pub validity_period_nanoseconds: U64,
+    /// Whether the Role uses the contract-wide default configuration rather than its own.
+    pub is_default: bool,
+    /// Number of accounts currently holding the Role.
+    pub member_count: u32,
+    /// Minimum number of accounts that must keep holding the Role. Revocations that would bring
+    /// the Role below it are rejected.
+    pub min_member_count: u32,
+}
@@ -0,0 +1,93 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::fiat_token_action::FiatTokenAction;
+use crate::role::Role;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::{Base64VecU8, U64},
+    require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// The maximum length (in bytes) of the description of a multi-signature request.
+pub(crate) const MAX_DESCRIPTION_LENGTH: usize = 256;
+
+/// The maximum length (in bytes) of the reference hash of a multi-signature request, e.g. the hash
+/// of an off-chain approval document.
+pub(crate) const MAX_REFERENCE_HASH_LENGTH: usize = 64;
+
+/// Context given by the creator of a multi-signature request, such as a ticket ID or reason, and a
+/// hash of the off-chain approval document. Stored alongside the request, keyed by its ID.
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+pub(crate) struct MultisigRequestMetadata {
+    pub description: Option<String>,
+    pub reference_hash: Option<Base64VecU8>,
+}
+
+impl MultisigRequestMetadata {
+    /// Verifies that the description and reference hash do not exceed their length limits.
+    pub(crate) fn validate(&self) {
+        if let Some(description) = &self.description {
+            require!(
+                description.len() <= MAX_DESCRIPTION_LENGTH,
+                format!(
+                    "FiatToken: description cannot be longer than {MAX_DESCRIPTION_LENGTH} bytes"
+                )
+            );
+        }
+        if let Some(reference_hash) = &self.reference_hash {
+            require!(
+                !reference_hash.0.is_empty() && reference_hash.0.len() <= MAX_REFERENCE_HASH_LENGTH,
+                format!(
+                    "FiatToken: reference hash must be between 1 and {MAX_REFERENCE_HASH_LENGTH} bytes"
+                )
+            );
+        }
+    }
+}
+
+/// Read-only representation of a pending multi-signature request, returned by the multisig
+/// view functions so that signers can inspect a request before approving it.
+#[derive(Serialize, Deserialize)]
+pub struct MultisigRequestView {
+    pub request_id: u32,
+    /// The action that will be performed once the request is executed.
+    pub action: FiatTokenAction,
+    /// The most privileged of the [`Role`]s required by the request, which for a non-batch
+    /// request is the only one.
+    pub role_required: Role,
+    /// The [`Role`]s required to approve, execute or remove the request.
+    pub roles_required: Vec<Role>,
+    /// Human-readable context given by the creator of the request, e.g. a ticket ID or reason.
+    pub description: Option<String>,
+    /// Hash of the off-chain document approving the request, if any.
+    pub reference_hash: Option<Base64VecU8>,
+    /// Accounts that have approved the request thus far.
+    pub approved_by: Vec<AccountId>,
+    /// Block timestamp (in nanoseconds) at which the request was created.
+    pub created_at: U64,
+    /// Block timestamp (in nanoseconds) after which the request can no longer be approved or
+    /// executed, and can be removed. `None` if requests have perpetual validity.
+    pub expires_at: Option<U64>,
+    /// Earliest block timestamp (in nanoseconds) at which the request can be executed, taking the
+    /// timelock of sensitive actions into account. `None` until the approval threshold is reached.
+    pub executable_at: Option<U64>,
+    /// Whether the request has sufficient approvals, is past its timelock (if any) and is still
+    /// within its validity period.
+    pub is_executable: bool,
+}
@@ -0,0 +1,72 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Defines the classes of state-changing operations that can be paused independently of each
+/// other, e.g. to halt minting without freezing every holder's transfers. Each class is stored as
+/// a bit of the contract's pause flags, so *new classes must be added to the bottom of the enum
+/// list*, otherwise the meaning of the stored flags will change.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub enum PausableOperation {
+    Transfer,
+    TransferCall,
+    Mint,
+    Burn,
+    Approve,
+    TransferFrom,
+    Storage,
+}
+
+/// Every [`PausableOperation`], in the order of their flags.
+pub(crate) const PAUSABLE_OPERATIONS: [PausableOperation; 7] = [
+    PausableOperation::Transfer,
+    PausableOperation::TransferCall,
+    PausableOperation::Mint,
+    PausableOperation::Burn,
+    PausableOperation::Approve,
+    PausableOperation::TransferFrom,
+    PausableOperation::Storage,
+];
+
+impl PausableOperation {
+    /// Returns the bit representing the [`PausableOperation`] in the contract's pause flags.
+    pub(crate) fn flag(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Returns the pause flags with every given [`PausableOperation`] set.
+    /// * `operations`  - The operations to set the flags of.
+    pub(crate) fn to_flags(operations: &[PausableOperation]) -> u8 {
+        operations
+            .iter()
+            .fold(0, |flags, operation| flags | operation.flag())
+    }
+
+    /// Returns the [`PausableOperation`]s whose flags are set.
+    /// * `flags`   - The pause flags.
+    pub(crate) fn from_flags(flags: u8) -> Vec<PausableOperation> {
+        PAUSABLE_OPERATIONS
+            .into_iter()
+            .filter(|operation| flags & operation.flag() != 0)
+            .collect()
+    }
+}
@@ -16,9 +16,18 @@
 
 use crate::fiat_token::Contract;
 use crate::role::Role;
+use crate::role_expiration::is_role_expired;
 
 use near_sdk::{env, require, AccountId};
 use near_sdk_contract_tools::rbac::Rbac;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The [`Role`]s on whose behalf the multi-signature request being executed acts. Their
+    /// approvals have already been verified, so that the actions of a batch can be executed by an
+    /// account holding only one of the Roles they require.
+    static MULTISIG_EXECUTION_ROLES: RefCell<Vec<Role>> = const { RefCell::new(Vec::new()) };
+}
 
 pub(crate) fn require_not_blocklisted(account_id: &AccountId) {
     require!(
@@ -27,10 +36,46 @@ pub(crate) fn require_not_blocklisted(account_id: &AccountId) {
     )
 }
 
-/// Throws if called by any account that does not have the specified [`Role`].
+/// Returns true if an account holds the specified [`Role`], and its grant of the Role has not
+/// expired.
+pub(crate) fn has_active_role(account_id: &AccountId, role: &Role) -> bool {
+    <Contract as Rbac>::has_role(account_id, role) && !is_role_expired(account_id, role)
+}
+
+/// Throws if called by any account that does not have the specified [`Role`], or whose
+/// time-bounded grant of the Role has expired, unless the Role is one of those on whose behalf a
+/// multi-signature request is being executed.
 pub(crate) fn require_only(role: Role) {
     require!(
-        <Contract as Rbac>::has_role(&env::predecessor_account_id(), &role),
+        has_active_role(&env::predecessor_account_id(), &role)
+            || MULTISIG_EXECUTION_ROLES.with(|roles| roles.borrow().contains(&role)),
         format!("FiatToken: caller is not a {role}")
     );
 }
+
+/// Runs `execute` on behalf of the given [`Role`]s, e.g. the actions of a multi-signature request
+/// whose approvals by each of them have been verified.
+/// * `roles`   - The Roles that [`require_only`] accepts while `execute` runs.
+/// * `execute` - The function to run.
+pub(crate) fn with_multisig_execution_roles<T>(roles: Vec<Role>, execute: impl FnOnce() -> T) -> T {
+    MULTISIG_EXECUTION_ROLES.with(|execution_roles| *execution_roles.borrow_mut() = roles);
+    let output: T = execute();
+    MULTISIG_EXECUTION_ROLES.with(|execution_roles| execution_roles.borrow_mut().clear());
+    output
+}
+
+/// Throws if called by any account that does not have at least one of the specified [`Role`]s.
+pub(crate) fn require_any(roles: &[Role]) {
+    let account_id: AccountId = env::predecessor_account_id();
+    require!(
+        roles.iter().any(|role| has_active_role(&account_id, role)),
+        format!(
+            "FiatToken: caller is not a {}",
+            roles
+                .iter()
+                .map(|role| role.to_string())
+                .collect::<Vec<String>>()
+                .join(" or ")
+        )
+    );
+}
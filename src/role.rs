@@ -25,7 +25,17 @@ use std::fmt::Display;
 /// `#[simple_multisig]`. When adding new roles, *make sure they are added to the bottom of the
 /// enum list*, otherwise when the contract is being migrated other accounts will lose their roles.
 #[derive(
-    Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshStorageKey,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshStorageKey,
 )]
 pub enum Role {
     Multisig,
@@ -37,8 +47,57 @@ pub enum Role {
     Owner,
     Pauser,
     Blocklisted, // This was added after deployment, so it has to be at the bottom.
+    Allowlisted,
+    AllowlistManager, // Non-multisig
 }
 
+/// Every [`Role`], in the order of the enum list.
+pub(crate) const ROLES: [Role; 11] = [
+    Role::Multisig,
+    Role::Admin,
+    Role::Blocklister,
+    Role::Controller,
+    Role::MasterMinter,
+    Role::Minter,
+    Role::Owner,
+    Role::Pauser,
+    Role::Blocklisted,
+    Role::Allowlisted,
+    Role::AllowlistManager,
+];
+
+/// The [`Role`]s whose actions are performed through multi-signature requests, each of which can
+/// have its own approval threshold and validity period.
+pub(crate) const MULTISIG_ROLES: [Role; 5] = [
+    Role::Admin,
+    Role::Controller,
+    Role::MasterMinter,
+    Role::Owner,
+    Role::Pauser,
+];
+
+/// The [`MULTISIG_ROLES`] from the most to the least privileged.
+pub(crate) const MULTISIG_ROLES_BY_PRIVILEGE: [Role; 5] = [
+    Role::Admin,
+    Role::Owner,
+    Role::MasterMinter,
+    Role::Controller,
+    Role::Pauser,
+];
+
+/// The pairs of [`Role`]s that a single account cannot hold at the same time, unless configured
+/// otherwise: the main multi-sig roles are kept separate from each other, and a Controller cannot
+/// be a Minter.
+pub(crate) const DEFAULT_ROLE_CONFLICTS: [(Role, Role); 7] = [
+    (Role::Admin, Role::MasterMinter),
+    (Role::Admin, Role::Owner),
+    (Role::Admin, Role::Pauser),
+    (Role::Controller, Role::Minter),
+    (Role::MasterMinter, Role::Owner),
+    (Role::MasterMinter, Role::Pauser),
+    (Role::Owner, Role::Pauser),
+];
+
 impl Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -54,6 +113,8 @@ impl Display for Role {
                 Role::Owner => "Owner",
                 Role::Pauser => "Pauser",
                 Role::Blocklisted => "Blocklisted",
+                Role::Allowlisted => "Allowlisted",
+                Role::AllowlistManager => "AllowlistManager",
             }
         )
     }
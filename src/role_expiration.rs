@@ -0,0 +1,54 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::fiat_token_storage_key::FiatTokenStorageKey;
+use crate::role::Role;
+use near_sdk::{env, store::LookupMap, AccountId};
+
+/// The block timestamps after which time-bounded [`Role`] grants expire. Like the [`Rbac`] roles
+/// themselves, they are read directly from storage so that [`require_only`] can check them without
+/// the contract's state.
+fn role_expirations() -> LookupMap<(AccountId, Role), u64> {
+    LookupMap::new(FiatTokenStorageKey::RoleExpirations)
+}
+
+/// Returns the block timestamp after which an account's [`Role`] grant expires, or `None` if the
+/// grant does not expire.
+/// * `account_id`  - The account holding the Role.
+/// * `role`        - The Role held by the account.
+pub(crate) fn get_role_expires_at(account_id: &AccountId, role: &Role) -> Option<u64> {
+    role_expirations()
+        .get(&(account_id.clone(), role.clone()))
+        .copied()
+}
+
+/// Sets or clears the block timestamp after which an account's [`Role`] grant expires.
+/// * `account_id`  - The account holding the Role.
+/// * `role`        - The Role held by the account.
+/// * `expires_at`  - The expiry of the grant, or `None` if it does not expire.
+pub(crate) fn set_role_expires_at(account_id: &AccountId, role: &Role, expires_at: Option<u64>) {
+    role_expirations().set((account_id.clone(), role.clone()), expires_at);
+}
+
+/// Returns true if an account's [`Role`] grant has expired.
+/// * `account_id`  - The account holding the Role.
+/// * `role`        - The Role held by the account.
+pub(crate) fn is_role_expired(account_id: &AccountId, role: &Role) -> bool {
+    match get_role_expires_at(account_id, role) {
+        Some(expires_at) => env::block_timestamp() >= expires_at,
+        None => false,
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::role::Role;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U64,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Read-only representation of a multi-sig [`Role`] offered to an account, which must be accepted
+/// by that account before it is granted.
+#[derive(Serialize, Deserialize)]
+pub struct RoleOfferView {
+    pub account_id: AccountId,
+    pub role: Role,
+    /// Block timestamp after which the offer can no longer be accepted, and can be removed.
+    pub expires_at: U64,
+    /// Whether the offer has expired.
+    pub is_expired: bool,
+    /// Block timestamp after which the Role expires once granted, if the grant is time-bounded.
+    pub role_expires_at: Option<U64>,
+}
+
+/// A multi-sig [`Role`] offered to an account and not accepted yet.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct RoleOffer {
+    /// Block timestamp after which the offer can no longer be accepted.
+    pub expires_at: u64,
+    /// Block timestamp after which the Role expires once granted, if the grant is time-bounded.
+    pub role_expires_at: Option<u64>,
+}
@@ -0,0 +1,37 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Defines what happens when configuring or increasing a minter allowance brings the sum of the
+/// outstanding minter allowances plus the total supply above the max total supply. Minting above
+/// the max total supply always fails. *New policies must be added to the bottom of the enum list*,
+/// otherwise the stored policy will change meaning.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub enum SupplyCapPolicy {
+    /// The allowance is configured without further notice.
+    Ignore,
+    /// The allowance is configured, and a `minter_allowances_exceed_max_total_supply` event is
+    /// emitted.
+    Warn,
+    /// Configuring the allowance fails.
+    Fail,
+}
@@ -14,24 +14,30 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 use near_contract_standards::storage_management::StorageBalance;
-use near_sdk::json_types::U128;
+use near_sdk::json_types::{U128, U64};
 use near_sdk::{
     base64,
-    serde_json::{json, Value},
+    serde::de::DeserializeOwned,
+    serde_json::{self, json, Value},
     AccountId,
 };
 use near_sdk_contract_tools::standard::nep148::FungibleTokenMetadata;
 use near_workspaces::{types::NearToken, Account, Contract};
 
-const FIAT_TOKEN_WASM: &[u8] = include_bytes!("./data/fiat_token.wasm");
+// Previous release of the contract, to test upgrading from it.
+const PREVIOUS_FIAT_TOKEN_WASM: &[u8] = include_bytes!("./data/fiat_token.wasm");
+// The contracts below are built from source by `make`.
+const FIAT_TOKEN_WASM_PATH: &str = "./target/wasm32-unknown-unknown/release/fiat_token.wasm";
 // Upgraded version of the contract that changes the multi-sig request's validity period to 1 ns.
-const UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM: &[u8] =
-    include_bytes!("./data/1ns_validity_period.wasm");
+const UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM_PATH: &str =
+    "./target/upgrade_fixtures/1ns_validity_period.wasm";
 // Upgraded version of the contract that changes the token name to "USDC V2", adds a was_upgraded
 // field exposed by a was_contract_upgraded function, and also increases the approval threshold
 // from 2 to 3.
-const UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM: &[u8] =
-    include_bytes!("./data/new_struct_and_name_3_approvals.wasm");
+const UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH: &str =
+    "./target/upgrade_fixtures/new_struct_and_name_3_approvals.wasm";
+const MOCK_COMPLIANCE_ORACLE_WASM_PATH: &str =
+    "./target/wasm32-unknown-unknown/release/mock_compliance_oracle.wasm";
 const NUM_REQUIRED_ACCOUNTS: usize = 14;
 const ACCOUNT_STORAGE_COST: u128 = 1250000000000000000000;
 
@@ -99,9 +105,14 @@ async fn setup(extra_accounts: usize, wasm: &[u8]) -> Setup {
     Setup { contract, accounts }
 }
 
+/// Reads a contract built by `make`.
+fn read_wasm(path: &str) -> Vec<u8> {
+    std::fs::read(path).unwrap_or_else(|_| panic!("{path} not found, build it with `make`"))
+}
+
 #[tokio::test]
 async fn test_contract_init() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let contract_account: &Account = &accounts[0];
     let admin1: &Account = &accounts[1];
     let admin2: &Account = &accounts[2];
@@ -191,7 +202,7 @@ async fn test_contract_init() {
 
 #[tokio::test]
 async fn test_configure_minter_allowance_mint_and_burn() {
-    let Setup { contract, accounts } = setup(4, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(4, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     // We need 2/3 master minters to be able to configure a controller, and at least
     // 2/3 controllers to control a minter.
@@ -255,7 +266,7 @@ async fn test_configure_minter_allowance_mint_and_burn() {
 
 #[tokio::test]
 async fn test_increase_and_decrease_minter_allowance() {
-    let Setup { contract, accounts } = setup(4, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(4, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
     let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
@@ -317,7 +328,7 @@ async fn test_increase_and_decrease_minter_allowance() {
 #[tokio::test]
 #[should_panic = "FiatToken: attempted to overflow minter allowance"]
 async fn test_increase_minter_allowance_attempted_to_overflow() {
-    let Setup { contract, accounts } = setup(4, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(4, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
     let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
@@ -347,7 +358,7 @@ async fn test_increase_minter_allowance_attempted_to_overflow() {
 #[tokio::test]
 #[should_panic = "FiatToken: attempted to underflow minter allowance"]
 async fn test_decrease_minter_allowance_attempted_to_underflow() {
-    let Setup { contract, accounts } = setup(4, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(4, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
     let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
@@ -376,7 +387,7 @@ async fn test_decrease_minter_allowance_attempted_to_underflow() {
 
 #[tokio::test]
 async fn test_approve_increase_decrease_allowance_transfer_from() {
-    let Setup { contract, accounts } = setup(6, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(6, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
@@ -493,7 +504,7 @@ async fn test_approve_increase_decrease_allowance_transfer_from() {
 
 #[tokio::test]
 async fn test_approve_twice_transfer_from() {
-    let Setup { contract, accounts } = setup(6, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(6, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
@@ -571,7 +582,7 @@ async fn test_approve_twice_transfer_from() {
 
 #[tokio::test]
 async fn test_approve_different_holders_same_spender_should_be_different() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     let token_holder2 = &accounts[2];
@@ -648,7 +659,7 @@ async fn test_approve_different_holders_same_spender_should_be_different() {
 
 #[tokio::test]
 async fn test_approval_same_holders_different_spender_should_be_different() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     let spender2 = &accounts[2];
@@ -727,7 +738,7 @@ async fn test_approval_same_holders_different_spender_should_be_different() {
 #[tokio::test]
 #[should_panic = "FiatToken: transfer amount exceeds allowance"]
 async fn test_transfer_from_exceeds_allowance() {
-    let Setup { contract, accounts } = setup(6, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(6, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     // We need 2/3 master minters to be able to configure a controller, and at least
     // 2/3 controllers to control a minter in order to mint.
@@ -784,7 +795,7 @@ async fn test_transfer_from_exceeds_allowance() {
 #[tokio::test]
 #[should_panic = "FiatToken: must approve initial allowance before incrementing"]
 async fn test_increase_allowance_not_previously_approved() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     increase_allowance(contract.clone(), token_holder.clone(), spender.clone(), 5).await;
@@ -793,7 +804,7 @@ async fn test_increase_allowance_not_previously_approved() {
 #[tokio::test]
 #[should_panic = "FiatToken: allowance increment must be greater than 0"]
 async fn test_increase_allowance_bad_increment() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     let allowance: u128 = 1234567;
@@ -810,7 +821,7 @@ async fn test_increase_allowance_bad_increment() {
 #[tokio::test]
 #[should_panic = "FiatToken: allowance decrement must be greater than 0"]
 async fn test_increase_allowance_bad_decrement() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     let allowance: u128 = 1234567;
@@ -827,7 +838,7 @@ async fn test_increase_allowance_bad_decrement() {
 #[tokio::test]
 #[should_panic = "FiatToken: must approve initial allowance before decrementing"]
 async fn test_decrease_allowance_not_previously_approved() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     decrease_allowance(contract.clone(), token_holder.clone(), spender.clone(), 5).await;
@@ -836,7 +847,7 @@ async fn test_decrease_allowance_not_previously_approved() {
 #[tokio::test]
 #[should_panic = "FiatToken: attempted to underflow allowance"]
 async fn test_decrease_allowance_attempted_to_underflow() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let token_holder = &accounts[0];
     let spender = &accounts[1];
     let allowance: u128 = 1234567;
@@ -859,7 +870,7 @@ async fn test_decrease_allowance_attempted_to_underflow() {
 #[tokio::test]
 #[should_panic = "is blocklisted"]
 async fn test_approve_blocklisted_account_to_spend() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let token_holder = &accounts[2];
     let blocklisted_account = &accounts[3];
@@ -882,7 +893,7 @@ async fn test_approve_blocklisted_account_to_spend() {
 
 #[tokio::test]
 async fn test_approve_caller_blocklisted_then_unblocklisted() {
-    let Setup { contract, accounts } = setup(1, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(1, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let token_holder = &accounts[2];
     let blocklister = &accounts[4];
@@ -940,7 +951,7 @@ async fn test_approve_caller_blocklisted_then_unblocklisted() {
         .unwrap();
     assert_eq!(
         unblocklist_result.logs()[0],
-        format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"unblocklist\",\"data\":{{\"account_id\":{:?}}}}}", token_holder.id().as_str())
+        format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.1.0\",\"event\":\"unblocklist\",\"data\":{{\"account_id\":{:?},\"blocklister_id\":{:?}}}}}", token_holder.id().as_str(), blocklister.id().as_str())
     );
     unblocklist_result.unwrap();
 
@@ -973,7 +984,7 @@ async fn test_approve_caller_blocklisted_then_unblocklisted() {
 #[tokio::test]
 #[should_panic = "is blocklisted"]
 async fn test_transfer_from_caller_blocklisted() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let token_holder = &accounts[2];
     let blocklisted_account = &accounts[3];
@@ -1001,7 +1012,7 @@ async fn test_transfer_from_caller_blocklisted() {
 #[tokio::test]
 #[should_panic = "is blocklisted"]
 async fn test_transfer_from_from_blocklisted() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let token_holder = &accounts[2];
     let blocklisted_account = &accounts[3];
@@ -1029,7 +1040,7 @@ async fn test_transfer_from_from_blocklisted() {
 #[tokio::test]
 #[should_panic = "is blocklisted"]
 async fn test_transfer_from_to_blocklisted() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let token_holder = &accounts[2];
     let blocklisted_account = &accounts[3];
@@ -1056,7 +1067,7 @@ async fn test_transfer_from_to_blocklisted() {
 
 #[tokio::test]
 async fn test_ft_transfer() {
-    let Setup { contract, accounts } = setup(6, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(6, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
@@ -1138,10 +1149,249 @@ async fn test_ft_transfer() {
     successful_transfer_result.unwrap();
 }
 
+#[tokio::test]
+async fn test_compliance_oracle() {
+    let Setup { contract, accounts } = setup(8, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
+
+    let master_minter1 = &accounts[5];
+    let master_minter2 = &accounts[6];
+    let owner1 = &accounts[8];
+    let owner2 = &accounts[9];
+    let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
+    let controller2 = &accounts[NUM_REQUIRED_ACCOUNTS + 1];
+    let minter = &accounts[NUM_REQUIRED_ACCOUNTS + 2];
+    let token_holder = &accounts[NUM_REQUIRED_ACCOUNTS + 3];
+    let sanctioned_receiver = &accounts[NUM_REQUIRED_ACCOUNTS + 4];
+    let transfer_receiver = &accounts[NUM_REQUIRED_ACCOUNTS + 5];
+    let oracle_account = &accounts[NUM_REQUIRED_ACCOUNTS + 6];
+    let keeper = &accounts[NUM_REQUIRED_ACCOUNTS + 7];
+
+    configure_minter_allowance(
+        contract.clone(),
+        master_minter1.clone(),
+        master_minter2.clone(),
+        controller1.clone(),
+        controller2.clone(),
+        minter.clone(),
+        1234567,
+    )
+    .await;
+    mint(
+        contract.clone(),
+        minter.clone(),
+        token_holder.clone(),
+        12345,
+    )
+    .await;
+    for receiver in [sanctioned_receiver, transfer_receiver] {
+        receiver
+            .call(contract.id(), "storage_deposit")
+            .args_json(json!({ "account_id": receiver.id(), "registration_only": false}))
+            .deposit(NearToken::from_yoctonear(ACCOUNT_STORAGE_COST))
+            .transact()
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    // Deploy the mock oracle, and have the owners make it the token's compliance oracle.
+    let oracle_wasm: Vec<u8> = read_wasm(MOCK_COMPLIANCE_ORACLE_WASM_PATH);
+    let oracle: Contract = oracle_account.deploy(&oracle_wasm).await.unwrap().unwrap();
+    oracle.call("new").transact().await.unwrap().unwrap();
+    let update_compliance_oracle_args: Value = json!({
+        "action": json!({
+            "UpdateComplianceOracle": json!({
+                "compliance_oracle": oracle.id(),
+            })
+        })
+    });
+    do_multisig_action(
+        owner1.clone(),
+        owner2.clone(),
+        contract.clone(),
+        Some(update_compliance_oracle_args),
+    )
+    .await;
+
+    // Have the oracle blocklist one of the receivers.
+    oracle
+        .call("set_blocklisted")
+        .args_json(json!({ "account_id": sanctioned_receiver.id(), "blocklisted": true }))
+        .transact()
+        .await
+        .unwrap()
+        .unwrap();
+
+    // ft_transfer_call consults the oracle before transferring, so it fails right away.
+    let transfer_amount: u128 = 124;
+    let blocked_transfer_call_result = token_holder
+        .call(contract.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": sanctioned_receiver.id(),
+            "amount": U128::from(transfer_amount),
+            "msg": "",
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .max_gas()
+        .transact()
+        .await
+        .unwrap();
+    assert!(blocked_transfer_call_result.is_failure());
+
+    // ft_transfer_call to a receiver that is not blocklisted goes through the oracle and transfers
+    // the tokens, which are refunded as the receiver does not implement ft_on_transfer.
+    let transfer_call_result = token_holder
+        .call(contract.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": transfer_receiver.id(),
+            "amount": U128::from(transfer_amount),
+            "msg": "",
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .max_gas()
+        .transact()
+        .await
+        .unwrap();
+    assert_eq!(
+        transfer_call_result.logs()[0],
+        format!("EVENT_JSON:{{\"standard\":\"nep141\",\"version\":\"1.0.0\",\"event\":\"ft_transfer\",\"data\":[{{\"old_owner_id\":{:?},\"new_owner_id\":{:?},\"amount\":\"{:?}\"}}]}}", token_holder.id().as_str(), transfer_receiver.id().as_str(), transfer_amount)
+    );
+    transfer_call_result.unwrap();
+
+    // ft_transfer relies on the local mirror of the oracle's blocklist, which is not synced yet.
+    token_holder
+        .call(contract.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": sanctioned_receiver.id(),
+            "amount": U128::from(transfer_amount),
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Anyone can sync the mirror from the oracle.
+    keeper
+        .call(contract.id(), "sync_blocklist_from_oracle")
+        .args_json(json!({ "account_ids": [sanctioned_receiver.id()] }))
+        .max_gas()
+        .transact()
+        .await
+        .unwrap()
+        .unwrap();
+    let is_blocklisted_by_oracle: bool = keeper
+        .call(contract.id(), "is_blocklisted_by_oracle")
+        .args_json(json!({ "account_id": sanctioned_receiver.id() }))
+        .transact()
+        .await
+        .unwrap()
+        .json::<bool>()
+        .unwrap();
+    assert!(is_blocklisted_by_oracle);
+
+    // Once synced, ft_transfer fails too.
+    let blocked_transfer_result = token_holder
+        .call(contract.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": sanctioned_receiver.id(),
+            "amount": U128::from(transfer_amount),
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await
+        .unwrap();
+    assert!(blocked_transfer_result.is_failure());
+
+    let sanctioned_receiver_balance: U128 = keeper
+        .call(contract.id(), "ft_balance_of")
+        .args_json(json!({ "account_id": sanctioned_receiver.id() }))
+        .transact()
+        .await
+        .unwrap()
+        .json::<U128>()
+        .unwrap();
+    assert_eq!(sanctioned_receiver_balance, U128::from(transfer_amount));
+}
+
+#[tokio::test]
+async fn test_views_while_paused() {
+    let Setup { contract, accounts } = setup(6, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
+
+    let master_minter1 = &accounts[5];
+    let master_minter2 = &accounts[6];
+    let pauser1 = &accounts[11];
+    let pauser2 = &accounts[12];
+    let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
+    let controller2 = &accounts[NUM_REQUIRED_ACCOUNTS + 1];
+    let minter = &accounts[NUM_REQUIRED_ACCOUNTS + 2];
+    let token_holder = &accounts[NUM_REQUIRED_ACCOUNTS + 3];
+
+    configure_minter_allowance(
+        contract.clone(),
+        master_minter1.clone(),
+        master_minter2.clone(),
+        controller1.clone(),
+        controller2.clone(),
+        minter.clone(),
+        1234567,
+    )
+    .await;
+    mint(
+        contract.clone(),
+        minter.clone(),
+        token_holder.clone(),
+        12345,
+    )
+    .await;
+
+    // Pause the contract.
+    do_multisig_action(
+        pauser1.clone(),
+        pauser2.clone(),
+        contract.clone(),
+        Some(json!({ "action": "Pause" })),
+    )
+    .await;
+    let is_paused: bool = token_holder
+        .view(contract.id(), "is_paused")
+        .await
+        .unwrap()
+        .json::<bool>()
+        .unwrap();
+    assert!(is_paused);
+
+    // View methods keep answering while paused.
+    let balance: U128 = token_holder
+        .view(contract.id(), "ft_balance_of")
+        .args_json(json!({ "account_id": token_holder.id() }))
+        .await
+        .unwrap()
+        .json::<U128>()
+        .unwrap();
+    assert_eq!(balance, U128::from(12345));
+    let total_supply: U128 = token_holder
+        .view(contract.id(), "ft_total_supply")
+        .await
+        .unwrap()
+        .json::<U128>()
+        .unwrap();
+    assert_eq!(total_supply, U128::from(12345));
+
+    // State-changing methods are halted.
+    let mint_result = minter
+        .call(contract.id(), "mint")
+        .args_json(json!({ "to": token_holder.id(), "amount": U128::from(1) }))
+        .transact()
+        .await
+        .unwrap();
+    assert!(mint_result.is_failure());
+}
+
 #[tokio::test]
 #[should_panic = "FiatToken: mint amount exceeds minter allowance"]
 async fn test_mint_exceeds_allowance() {
-    let Setup { contract, accounts } = setup(3, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(3, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
     let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
@@ -1163,7 +1413,7 @@ async fn test_mint_exceeds_allowance() {
 #[tokio::test]
 #[should_panic = "FiatToken: burn amount exceeds balance"]
 async fn test_burn_exceeds_allowance() {
-    let Setup { contract, accounts } = setup(3, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(3, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
     let master_minter1 = &accounts[5];
     let master_minter2 = &accounts[6];
     let controller1 = &accounts[NUM_REQUIRED_ACCOUNTS];
@@ -1184,14 +1434,14 @@ async fn test_burn_exceeds_allowance() {
 
 #[tokio::test]
 async fn test_upgrade() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     // We need 2/3 admins to be able to upgrade.
     let admin1 = &accounts[1];
     let admin2 = &accounts[2];
     upgrade_contract(
         contract.clone(),
-        UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM,
+        &read_wasm(UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH),
         admin1.clone(),
         admin2.clone(),
     )
@@ -1221,7 +1471,9 @@ async fn test_upgrade() {
     let approved_for_upgrade = admin2
         .call(contract.id(), "upgrade")
         .max_gas()
-        .args(UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM.to_vec())
+        .args(read_wasm(
+            UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH,
+        ))
         .transact()
         .await
         .unwrap();
@@ -1231,14 +1483,14 @@ async fn test_upgrade() {
 #[tokio::test]
 #[should_panic = "ExecutionEligibility(InsufficientApprovals { current: 2, required: 3 }"]
 async fn test_upgrade_to_three_approvals() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     // We need 2/3 admins to be able to upgrade.
     let admin1 = &accounts[1];
     let admin2 = &accounts[2];
     upgrade_contract(
         contract.clone(),
-        UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM,
+        &read_wasm(UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH),
         admin1.clone(),
         admin2.clone(),
     )
@@ -1261,10 +1513,11 @@ async fn test_upgrade_to_three_approvals() {
 #[tokio::test]
 #[should_panic = "Smart contract panicked: FiatToken: not approved for upgrade"]
 async fn test_upgrade_not_approved_for_upgrade() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let admin1 = &accounts[1];
-    let upgraded_contract_vec: Vec<u8> = UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM.to_vec();
+    let upgraded_contract_vec: Vec<u8> =
+        read_wasm(UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH);
     let upgraded_contract_b64: String = base64::encode(upgraded_contract_vec);
     let upgrade_args_json: Value = json!({ "code": upgraded_contract_b64 });
 
@@ -1282,10 +1535,11 @@ async fn test_upgrade_not_approved_for_upgrade() {
 #[tokio::test]
 #[should_panic = "Smart contract panicked: FiatToken: caller is not a Admin"]
 async fn test_upgrade_not_admin() {
-    let Setup { contract, accounts } = setup(0, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(0, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let blocklister = &accounts[4];
-    let upgraded_contract_vec: Vec<u8> = UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM.to_vec();
+    let upgraded_contract_vec: Vec<u8> =
+        read_wasm(UPGRADED_FIAT_TOKEN_NEW_NAME_3_APPROVALS_WASM_PATH);
     let upgraded_contract_b64: String = base64::encode(upgraded_contract_vec);
     let upgrade_args_json: Value = json!({ "code": upgraded_contract_b64 });
 
@@ -1303,7 +1557,7 @@ async fn test_upgrade_not_admin() {
 #[tokio::test]
 #[should_panic = "ApprovalError(RequestExpired(RequestExpiredError))"]
 async fn test_expired_request() {
-    let Setup { contract, accounts } = setup(1, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(1, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     // We need 2/3 admins to be able to upgrade.
     let admin1 = &accounts[1];
@@ -1312,7 +1566,7 @@ async fn test_expired_request() {
     // Upgrade contract to have multi-sig requests with validity periods of 1 ns.
     upgrade_contract(
         contract.clone(),
-        UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM,
+        &read_wasm(UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM_PATH),
         admin1.clone(),
         admin2.clone(),
     )
@@ -1346,14 +1600,14 @@ async fn test_expired_request() {
 
 #[tokio::test]
 async fn test_remove_expired_request() {
-    let Setup { contract, accounts } = setup(2, FIAT_TOKEN_WASM).await;
+    let Setup { contract, accounts } = setup(2, &read_wasm(FIAT_TOKEN_WASM_PATH)).await;
 
     let admin1 = &accounts[1];
     let admin2 = &accounts[2];
     // Upgrade contract to have multi-sig requests with validity periods of 1 ns.
     upgrade_contract(
         contract.clone(),
-        UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM,
+        &read_wasm(UPGRADED_FIAT_TOKEN_1NS_VALIDITY_PERIOD_WASM_PATH),
         admin1.clone(),
         admin2.clone(),
     )
@@ -1408,6 +1662,119 @@ async fn test_remove_expired_request() {
     assert_eq!(first_request_id + 1, second_request_id);
 }
 
+#[tokio::test]
+async fn test_upgrade_from_previous_release() {
+    let Setup { contract, accounts } = setup(2, PREVIOUS_FIAT_TOKEN_WASM).await;
+
+    let admin1 = &accounts[1];
+    let admin2 = &accounts[2];
+    let owner1 = &accounts[8];
+    let owner2 = &accounts[9];
+    let pauser1 = &accounts[11];
+    let pauser2 = &accounts[12];
+    let blocklister = &accounts[NUM_REQUIRED_ACCOUNTS];
+    let blocklisted = &accounts[NUM_REQUIRED_ACCOUNTS + 1];
+
+    // Set a blocklister, blocklist an account and pause the previous release.
+    do_multisig_action(
+        owner1.clone(),
+        owner2.clone(),
+        contract.clone(),
+        Some(json!({
+            "action": json!({
+                "UpdateBlocklister": json!({ "new_blocklister_id": blocklister.id() })
+            })
+        })),
+    )
+    .await;
+    blocklister
+        .call(contract.id(), "blocklist")
+        .args_json(json!({ "account_id": blocklisted.id() }))
+        .transact()
+        .await
+        .unwrap()
+        .unwrap();
+    do_multisig_action(
+        pauser1.clone(),
+        pauser2.clone(),
+        contract.clone(),
+        Some(json!({ "action": "Pause" })),
+    )
+    .await;
+
+    upgrade_contract(
+        contract.clone(),
+        &read_wasm(FIAT_TOKEN_WASM_PATH),
+        admin1.clone(),
+        admin2.clone(),
+    )
+    .await;
+
+    // Assert that the Roles carried over, the blocklister now being a member of the Blocklister
+    // Role.
+    for (function, members) in [
+        ("admins", &accounts[1..4]),
+        ("master_minters", &accounts[5..8]),
+        ("owners", &accounts[8..11]),
+        ("pausers", &accounts[11..14]),
+        ("blocklisters", std::slice::from_ref(blocklister)),
+    ] {
+        let member_ids: Vec<String> = view(&contract, function, json!({})).await;
+        assert_eq!(
+            member_ids,
+            members
+                .iter()
+                .map(|member| member.id().to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+    let is_blocklisted: bool = view(
+        &contract,
+        "is_blocklisted",
+        json!({ "account_id": blocklisted.id() }),
+    )
+    .await;
+    assert!(is_blocklisted);
+
+    // Assert that the pause now covers every operation.
+    let paused_operations: Value = view(&contract, "paused_operations", json!({})).await;
+    assert_eq!(
+        paused_operations,
+        json!([
+            "Transfer",
+            "TransferCall",
+            "Mint",
+            "Burn",
+            "Approve",
+            "TransferFrom",
+            "Storage"
+        ])
+    );
+
+    // Assert that the new state got its defaults.
+    let role_conflicts: Value = view(&contract, "role_conflicts", json!({})).await;
+    assert_eq!(
+        role_conflicts,
+        json!([
+            ["Admin", "MasterMinter"],
+            ["Admin", "Owner"],
+            ["Admin", "Pauser"],
+            ["Controller", "Minter"],
+            ["MasterMinter", "Owner"],
+            ["MasterMinter", "Pauser"],
+            ["Owner", "Pauser"]
+        ])
+    );
+    let multisig_timelock: U64 = view(&contract, "get_multisig_timelock", json!({})).await;
+    assert_eq!(multisig_timelock, U64::from(0));
+    let compliance_oracle: Option<String> = view(&contract, "compliance_oracle", json!({})).await;
+    assert_eq!(compliance_oracle, None);
+    let max_total_supply: Option<U128> = view(&contract, "max_total_supply", json!({})).await;
+    assert_eq!(max_total_supply, None);
+    let is_approved_for_upgrade: bool = view(&contract, "is_approved_for_upgrade", json!({})).await;
+    assert!(!is_approved_for_upgrade);
+}
+
 // Helper function to configure controllers and to configure a minter's allowance.
 async fn configure_minter_allowance(
     contract: Contract,
@@ -1681,9 +2048,28 @@ async fn blocklist(contract: Contract, blocklister: Account, account_to_blocklis
         .transact()
         .await
         .unwrap();
+    // The blocklist timestamp depends on the sandbox's blocks, so only its presence is checked.
+    let mut blocklist_event: Value = serde_json::from_str(
+        blocklist_result.logs()[0]
+            .strip_prefix("EVENT_JSON:")
+            .unwrap(),
+    )
+    .unwrap();
+    assert!(blocklist_event["data"]["blocklisted_at"].take().is_string());
     assert_eq!(
-        blocklist_result.logs()[0],
-        format!("EVENT_JSON:{{\"standard\":\"x-fiat-token\",\"version\":\"1.0.0\",\"event\":\"blocklist\",\"data\":{{\"account_id\":{:?}}}}}", account_to_blocklist.id().as_str())
+        blocklist_event,
+        json!({
+            "standard": "x-fiat-token",
+            "version": "1.1.0",
+            "event": "blocklist",
+            "data": {
+                "account_id": account_to_blocklist.id(),
+                "blocklister_id": blocklister.id(),
+                "reason": null,
+                "reference": null,
+                "blocklisted_at": null
+            }
+        })
     );
     blocklist_result.unwrap();
 }
@@ -1727,6 +2113,17 @@ async fn upgrade_contract(
     upgrade_result.unwrap();
 }
 
+/// Helper function to call a view function and parse its result.
+async fn view<T: DeserializeOwned>(contract: &Contract, function: &str, args_json: Value) -> T {
+    contract
+        .view(function)
+        .args_json(args_json)
+        .await
+        .unwrap()
+        .json::<T>()
+        .unwrap()
+}
+
 /// Helper function to bundle the creation and first approval of multi-sig requests.
 /// This is akin to bundling these two transactions into a vault run so that this multi-sig
 /// process is more optimized.
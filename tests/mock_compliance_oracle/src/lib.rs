@@ -0,0 +1,58 @@
+// Copyright 2023 Circle Internet Financial, LTD.  All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock compliance oracle used by the integration tests, answering whether accounts are
+//! blocklisted from a list that anyone can update.
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    near_bindgen,
+    store::LookupSet,
+    AccountId, PanicOnDefault,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+#[near_bindgen]
+pub struct Contract {
+    blocklisted: LookupSet<AccountId>,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            blocklisted: LookupSet::new(b"b"),
+        }
+    }
+
+    /// Adds an account to, or removes it from, the oracle's blocklist.
+    pub fn set_blocklisted(&mut self, account_id: AccountId, blocklisted: bool) {
+        if blocklisted {
+            self.blocklisted.insert(account_id);
+        } else {
+            self.blocklisted.remove(&account_id);
+        }
+    }
+
+    /// Returns, for each of the given accounts, whether it is blocklisted.
+    pub fn are_blocklisted(&self, account_ids: Vec<AccountId>) -> Vec<bool> {
+        account_ids
+            .iter()
+            .map(|account_id| self.blocklisted.contains(account_id))
+            .collect()
+    }
+}
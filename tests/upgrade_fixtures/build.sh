@@ -0,0 +1,18 @@
+#!/bin/sh
+# Builds the contracts the integration tests upgrade to. Each `<name>.patch` in this directory is applied to a copy of
+# the contract's current source, which is built into `target/upgrade_fixtures/<name>.wasm`. Run by `make`.
+set -e
+cd "$(dirname "$0")/../.."
+
+for patch in tests/upgrade_fixtures/*.patch; do
+	name=$(basename "$patch" .patch)
+	dir="target/upgrade_fixtures/$name"
+	rm -rf "$dir"
+	mkdir -p "$dir"
+	cp -r Cargo.toml Cargo.lock src "$dir"
+	# The copy is built on its own, without the workspace's other members.
+	sed -i '/^members = /d' "$dir/Cargo.toml"
+	patch -s -p1 -d "$dir" < "$patch"
+	(cd "$dir" && cargo build --target wasm32-unknown-unknown --release)
+	cp "$dir/target/wasm32-unknown-unknown/release/fiat_token.wasm" "target/upgrade_fixtures/$name.wasm"
+done